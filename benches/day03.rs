@@ -0,0 +1,125 @@
+//! Compares the day03 solver with the previous implementation, which counted
+//! each column in its own pass and filtered by rebuilding vectors, on
+//! generated reports.
+//!
+//! ```text
+//! cargo bench --bench day03 [-- <lines> [<runs>]]
+//! ```
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2021::{bench::Stats, bitset::BitSet, day03::Report, generators::get_generator};
+
+fn most_common_value_in_position<'a>(
+    values: impl IntoIterator<Item = &'a BitSet>,
+    pos: usize,
+) -> Option<bool> {
+    let mut count = 0isize;
+    for v in values {
+        match v.get(pos) {
+            true => count += 1,
+            false => count -= 1,
+        }
+    }
+
+    match count {
+        _ if count > 0 => Some(true),
+        _ if count < 0 => Some(false),
+        _ => None,
+    }
+}
+
+fn previous_gamma(report: &Report) -> BitSet {
+    let mut gamma_rate = BitSet::new(report.num_bits());
+    for i in 0..report.num_bits() {
+        gamma_rate.set(
+            i,
+            most_common_value_in_position(report.values(), i).unwrap(),
+        );
+    }
+    gamma_rate
+}
+
+fn previous_filter(report: &Report, most_common: bool) -> BitSet {
+    let mut candidates = report.values().iter().collect::<Vec<_>>();
+    let mut bit_position = report.num_bits();
+    while candidates.len() > 1 {
+        bit_position -= 1;
+        let keep = most_common_value_in_position(candidates.iter().copied(), bit_position)
+            .unwrap_or(true)
+            == most_common;
+        candidates.retain(|v| v.get(bit_position) == keep);
+    }
+    candidates[0].clone()
+}
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    Stats::new(samples)
+}
+
+fn main() {
+    let mut args = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != "--bench")
+        .map(|arg| {
+            arg.parse::<usize>()
+                .expect("usage: day03 [<lines> [<runs>]]")
+        });
+    let lines = args.next().unwrap_or(1_000_000);
+    let runs = args.next().unwrap_or(10);
+
+    let input = get_generator(3).unwrap().generate(1, lines);
+    let parse = time(runs, || input.parse::<Report>().unwrap());
+    let report: Report = input.parse().unwrap();
+    assert_eq!(report.gamma_and_epsilon().0, previous_gamma(&report));
+    assert_eq!(report.oxygen(), previous_filter(&report, true));
+    assert_eq!(report.co2(), previous_filter(&report, false));
+
+    println!(
+        "{} lines of {} bits, {} runs, median (min)",
+        report.values().len(),
+        report.num_bits(),
+        runs
+    );
+    println!(
+        "{:<14}  {:>22}",
+        "parse",
+        format!("{:.2?} ({:.2?})", parse.median, parse.min)
+    );
+    let phases = [
+        (
+            "gamma",
+            time(runs, || previous_gamma(&report)),
+            time(runs, || report.gamma_and_epsilon()),
+        ),
+        (
+            "oxygen + co2",
+            time(runs, || {
+                (
+                    previous_filter(&report, true),
+                    previous_filter(&report, false),
+                )
+            }),
+            time(runs, || (report.oxygen(), report.co2())),
+        ),
+    ];
+    println!("{:<14}  {:>22}  {:>22}", "", "previous", "current");
+    for (phase, previous, current) in phases {
+        println!(
+            "{:<14}  {:>22}  {:>22}",
+            phase,
+            format!("{:.2?} ({:.2?})", previous.median, previous.min),
+            format!("{:.2?} ({:.2?})", current.median, current.min)
+        );
+    }
+}
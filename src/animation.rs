@@ -0,0 +1,160 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::{
+    day11::Day11,
+    day20::{enhance, Day20},
+    day25::{Cucumber, Day25, Occupancy},
+    error::ParseError,
+    grid::Grid,
+    solution::Solution,
+};
+
+/// Days whose simulation can be animated.
+pub const ANIMATED_DAYS: [u8; 3] = [11, 20, 25];
+
+/// Day 11 octopuses rarely need more steps than this to flash all at once.
+const MAX_STEPS: usize = 1000;
+
+/// Steps of a simulation, rendered with ANSI colors.
+pub type Frames = Box<dyn Iterator<Item = String>>;
+
+/// Frames of the simulation of `day` on `input`, `None` if the day can't be
+/// animated.
+pub fn frames(day: u8, input: &str) -> Option<Result<Frames, ParseError>> {
+    match day {
+        11 => Some(octopuses(input)),
+        20 => Some(image_enhancement(input)),
+        25 => Some(sea_cucumbers(input)),
+        _ => None,
+    }
+}
+
+/// Draws every frame in place at the top of the terminal, waiting `delay`
+/// between them.
+pub fn play(frames: Frames, delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    // Clear the screen and hide the cursor while drawing
+    write!(out, "\x1b[2J\x1b[?25l")?;
+    for (i, frame) in frames.enumerate() {
+        if i > 0 {
+            std::thread::sleep(delay);
+        }
+        // Frames are drawn from the top left corner, clearing what a larger
+        // previous frame left below
+        write!(out, "\x1b[H{}\x1b[J", frame)?;
+        out.flush()?;
+    }
+    write!(out, "\x1b[?25h")?;
+    out.flush()
+}
+
+fn paint(code: &str, c: impl std::fmt::Display) -> String {
+    format!("\x1b[{}m{}\x1b[0m", code, c)
+}
+
+fn frame(title: String, grid: Grid<String>) -> String {
+    format!("{}\n{}", title, grid)
+}
+
+/// Steps until all octopuses flash at once, the ones that just flashed
+/// highlighted.
+fn octopuses(input: &str) -> Result<Frames, ParseError> {
+    let mut energy_levels = Day11::parse(input)?;
+    let render = |levels: &Grid<u8>| {
+        levels.map(|level| match level {
+            0 => paint("1;93", level),
+            _ => paint(&format!("38;5;{}", 236 + 2 * level), level),
+        })
+    };
+
+    let first = frame("step 0".to_string(), render(energy_levels.levels()));
+    let mut synced = false;
+    let steps = (1..=MAX_STEPS).map_while(move |step| {
+        if synced {
+            return None;
+        }
+        let num_flashes = energy_levels.step(1);
+        synced = num_flashes == energy_levels.levels().len();
+        let title = format!("step {}, {} flashes", step, num_flashes);
+        Some(frame(title, render(energy_levels.levels())))
+    });
+    Ok(Box::new(std::iter::once(first).chain(steps)))
+}
+
+/// The 50 enhancements of part 2.
+fn image_enhancement(input: &str) -> Result<Frames, ParseError> {
+    let (algorithm, image) = Day20::parse(input)?;
+    let frames = std::iter::successors(Some(image), move |image| {
+        Some(enhance(image.clone(), &algorithm))
+    })
+    .take(51)
+    .enumerate()
+    .map(|(step, image)| {
+        let title = format!(
+            "enhancement {}, {} pixels lit",
+            step,
+            image.num_pixels_lit()
+        );
+        let pixels = image.pixels().map(|pixel| match pixel {
+            true => paint("97", '#'),
+            false => paint("90", '.'),
+        });
+        frame(title, pixels)
+    });
+    Ok(Box::new(frames))
+}
+
+/// Steps until the herds stop moving.
+fn sea_cucumbers(input: &str) -> Result<Frames, ParseError> {
+    let mut map = Day25::parse(input)?;
+    let render = |positions: &Grid<Occupancy>| {
+        positions.map(|position| match position {
+            Occupancy::Busy(Cucumber::East) => paint("32", '>'),
+            Occupancy::Busy(Cucumber::South) => paint("36", 'v'),
+            Occupancy::Empty => paint("90", '.'),
+        })
+    };
+
+    let first = frame("step 0".to_string(), render(map.positions()));
+    let steps = (1..)
+        .map_while(move |step| map.step().then(|| (step, render(map.positions()))))
+        .map(|(step, positions)| frame(format!("step {}", step), positions));
+    Ok(Box::new(std::iter::once(first).chain(steps)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>
+";
+
+    #[test]
+    fn example() {
+        let steps = frames(25, INPUT_EXAMPLE)
+            .unwrap()
+            .unwrap()
+            .collect::<Vec<_>>();
+        // The 58th step is the first where nothing moves
+        assert_eq!(steps.len(), 58);
+        assert!(steps[57].starts_with("step 57\n\x1b[90m.\x1b[0m"));
+
+        let mut out = Vec::new();
+        play(Box::new(steps.into_iter()), Duration::ZERO, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[H").count(), 58);
+        assert!(out.ends_with("\x1b[?25h"));
+
+        assert!(frames(1, INPUT_EXAMPLE).is_none());
+    }
+}
@@ -0,0 +1,213 @@
+use std::{fs, path::PathBuf};
+
+use crate::get_day;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Expected answers of a recorded puzzle input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recorded {
+    pub input: PathBuf,
+    pub day: u8,
+    /// Expected answer of each part, `None` if not recorded
+    pub answers: [Option<String>; 2],
+}
+
+/// Answer of a recorded part after solving it again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartCheck {
+    pub part: usize,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl PartCheck {
+    pub fn is_match(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+impl Recorded {
+    /// Reads and solves the input again, returning the outcome of every
+    /// recorded part.
+    pub fn check(&self) -> Result<Vec<PartCheck>, String> {
+        let day = get_day(self.day).ok_or_else(|| format!("invalid day {}", self.day))?;
+        let input = fs::read_to_string(&self.input)
+            .map_err(|err| format!("failed to read {}: {}", self.input.display(), err))?;
+        let input = day
+            .parse(&input)
+            .map_err(|err| format!("{}: {}", self.input.display(), err))?;
+
+        Ok(self
+            .answers
+            .iter()
+            .zip(1..)
+            .filter_map(|(expected, part)| {
+                let expected = expected.clone()?;
+                let actual = day.solve(input.as_ref(), part);
+                Some(PartCheck {
+                    part,
+                    expected,
+                    actual,
+                })
+            })
+            .collect())
+    }
+}
+
+/// Parses an answers file. It is a small subset of TOML: one table per input
+/// file, with the day and the expected answer of each part.
+///
+/// ```toml
+/// ["inputs/day01.txt"]
+/// day = 1
+/// part1 = 1559
+/// part2 = "1600"
+/// ```
+pub fn parse(s: &str) -> Result<Vec<Recorded>, String> {
+    let mut recorded: Vec<Recorded> = Vec::new();
+
+    for (i, line) in s.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", i + 1, message);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let input = parse_value(header.trim()).ok_or_else(|| error("invalid table name"))?;
+            recorded.push(Recorded {
+                input: PathBuf::from(input),
+                day: 0,
+                answers: [None, None],
+            });
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected 'key = value'"))?;
+        let value = parse_value(value.trim()).ok_or_else(|| error("invalid value"))?;
+        let current = recorded
+            .last_mut()
+            .ok_or_else(|| error("key outside of an input table"))?;
+        match key.trim() {
+            "day" => {
+                current.day = value
+                    .parse()
+                    .ok()
+                    .filter(|day| get_day(*day).is_some())
+                    .ok_or_else(|| error("invalid day"))?
+            }
+            "part1" => current.answers[0] = Some(value),
+            "part2" => current.answers[1] = Some(value),
+            key => return Err(error(&format!("unknown key '{}'", key))),
+        }
+    }
+
+    if let Some(r) = recorded.iter().find(|r| r.day == 0) {
+        return Err(format!("missing day for {}", r.input.display()));
+    }
+    Ok(recorded)
+}
+
+/// Reads an answers file with [`parse`].
+pub fn read(path: &str) -> Result<Vec<Recorded>, String> {
+    let s = fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path, err))?;
+    parse(&s).map_err(|err| format!("{}: {}", path, err))
+}
+
+/// Integers or basic strings, the only values answers need.
+pub(crate) fn parse_value(s: &str) -> Option<String> {
+    if let Some(s) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = s.chars();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => value.push(match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    '"' => '"',
+                    '\\' => '\\',
+                    _ => return None,
+                }),
+                c => value.push(c),
+            }
+        }
+        // Only a comment may follow the closing quote
+        let rest = chars.as_str().trim_start();
+        (rest.is_empty() || rest.starts_with('#')).then_some(value)
+    } else {
+        let s = s.split('#').next().unwrap_or_default().trim();
+        s.parse::<i64>().ok().map(|_| s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS_EXAMPLE: &str = r##"# Recorded answers
+["inputs/day01.txt"]
+day = 1
+part1 = 1559
+part2 = "1600" # comment
+
+["inputs/day13_hard.txt"]
+day = 13
+part2 = "#  #\n####\n"
+"##;
+
+    #[test]
+    fn parse_answers() {
+        let recorded = parse(ANSWERS_EXAMPLE).unwrap();
+        assert_eq!(
+            recorded,
+            vec![
+                Recorded {
+                    input: PathBuf::from("inputs/day01.txt"),
+                    day: 1,
+                    answers: [Some("1559".to_string()), Some("1600".to_string())],
+                },
+                Recorded {
+                    input: PathBuf::from("inputs/day13_hard.txt"),
+                    day: 13,
+                    answers: [None, Some("#  #\n####\n".to_string())],
+                },
+            ]
+        );
+        assert_eq!(parse_value(r#""a\"\\b""#).unwrap(), "a\"\\b");
+
+        assert_eq!(
+            parse("day = 1").unwrap_err(),
+            "line 1: key outside of an input table"
+        );
+        assert_eq!(
+            parse("[\"a\"]\nday = 26").unwrap_err(),
+            "line 2: invalid day"
+        );
+        assert_eq!(
+            parse("[\"a\"]\npart1 = 1").unwrap_err(),
+            "missing day for a"
+        );
+    }
+
+    /// Slow in debug builds, run with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn recorded_answers() {
+        for recorded in read(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml")).unwrap() {
+            for check in recorded.check().unwrap() {
+                assert!(
+                    check.is_match(),
+                    "{} part {}: expected {}, got {}",
+                    recorded.input.display(),
+                    check.part,
+                    check.expected,
+                    check.actual
+                );
+            }
+        }
+    }
+}
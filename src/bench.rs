@@ -0,0 +1,140 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseError, json, solution::Day};
+
+/// Timing statistics over the runs of a phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        // Nearest-rank percentiles
+        let percentile = |p: usize| samples[(p * samples.len()).div_ceil(100).max(1) - 1];
+        Self {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// Statistics of a phase of a day: `parse`, `part1` or `part2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseStats {
+    pub day: u8,
+    pub phase: &'static str,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+/// Parses and solves `input` `runs` times, timing each phase separately.
+pub fn bench(day: &Day, input: &str, runs: usize) -> Result<Vec<PhaseStats>, ParseError> {
+    assert!(runs > 0);
+    let mut samples = vec![Vec::with_capacity(runs); day.parts + 1];
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = day.parse(input)?;
+        samples[0].push(start.elapsed());
+
+        for (part, samples) in samples.iter_mut().enumerate().skip(1) {
+            let start = Instant::now();
+            let answer = day.solve(parsed.as_ref(), part);
+            samples.push(start.elapsed());
+            std::hint::black_box(answer);
+        }
+    }
+
+    Ok(["parse", "part1", "part2"]
+        .into_iter()
+        .zip(samples)
+        .map(|(phase, samples)| PhaseStats {
+            day: day.day,
+            phase,
+            runs,
+            stats: Stats::new(samples),
+        })
+        .collect())
+}
+
+pub fn to_csv(results: &[PhaseStats]) -> String {
+    let mut csv = String::from("day,phase,runs,min_ns,median_ns,p95_ns\n");
+    for r in results {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            r.day,
+            r.phase,
+            r.runs,
+            r.stats.min.as_nanos(),
+            r.stats.median.as_nanos(),
+            r.stats.p95.as_nanos()
+        )
+        .unwrap();
+    }
+    csv
+}
+
+pub fn to_json(results: &[PhaseStats]) -> String {
+    let objects = results
+        .iter()
+        .map(|r| {
+            format!(
+                "{{\"day\": {}, \"phase\": {}, \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+                r.day,
+                json::string(r.phase),
+                r.runs,
+                r.stats.min.as_nanos(),
+                r.stats.median.as_nanos(),
+                r.stats.p95.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+    json::array(&objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day;
+
+    #[test]
+    fn stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(10),
+                p95: Duration::from_millis(19),
+            }
+        );
+        let single = Stats::new(vec![Duration::from_millis(3)]);
+        assert_eq!(single.median, Duration::from_millis(3));
+        assert_eq!(single.p95, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn bench_day() {
+        let day = get_day(1).unwrap();
+        let results = bench(day, "199\n200\n208\n", 3).unwrap();
+        let phases = results.iter().map(|r| r.phase).collect::<Vec<_>>();
+        assert_eq!(phases, ["parse", "part1", "part2"]);
+        assert!(results.iter().all(|r| r.day == 1 && r.runs == 3));
+
+        let csv = to_csv(&results);
+        assert_eq!(csv.lines().count(), 4);
+        assert!(csv.lines().nth(1).unwrap().starts_with("1,parse,3,"));
+        assert!(
+            to_json(&results).starts_with("[\n  {\"day\": 1, \"phase\": \"parse\", \"runs\": 3,")
+        );
+    }
+}
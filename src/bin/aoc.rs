@@ -0,0 +1,579 @@
+use std::time::{Duration, Instant};
+
+use advent_of_code_2021::{
+    animation, answers, bench,
+    fetch::{self, Fetched},
+    generators::get_generator,
+    get_day,
+    input::{self, InputSource},
+    json,
+    parallel::{self, Outcome},
+    solution::Day,
+    trace, DAYS,
+};
+
+const USAGE: &str = "Usage: aoc <day|all> [--part <1|2>] [--input <path|->] [--format <table|json>]
+       aoc <day|all> --parallel [--jobs <n>] [--part <1|2>] [--input <path|->]
+       aoc <11|20|25> --animate [--delay <ms>] [--input <path|->]
+       aoc bench <day|all> [--runs <n>] [--output <file.csv|file.json>] [--input <path|->]
+       aoc check [--answers <path>]
+       aoc gen <day> [--seed <n>] [--size <n>]
+       aoc fetch <day|all> [--config <path>] [--base-url <url>]
+
+Solving and bench modes also accept --trace <filter>, e.g. `--trace day23=debug`,
+which defaults to the AOC_TRACE environment variable.";
+
+#[derive(PartialEq)]
+enum Format {
+    Table,
+    Json,
+}
+
+struct Options {
+    days: Vec<&'static Day>,
+    part: Option<usize>,
+    input: Option<String>,
+    format: Format,
+    animate: bool,
+    /// Number of threads solving parts concurrently, if parallel
+    jobs: Option<usize>,
+    /// Pause between animation frames
+    delay: Duration,
+    trace: Option<trace::Filter>,
+    /// Only for bench mode
+    runs: usize,
+    output: Option<String>,
+}
+
+fn parse_args(args: &[String], bench: bool) -> Result<Options, String> {
+    let mut days = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Table;
+    let mut animate = false;
+    let mut jobs = None;
+    let mut trace = None;
+    let mut delay = Duration::from_millis(100);
+    let mut runs = 10;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" if !bench => {
+                let value = args.next().ok_or("--part requires a value")?;
+                match value.parse() {
+                    Ok(p @ (1 | 2)) => part = Some(p),
+                    _ => return Err(format!("invalid part: {}", value)),
+                }
+            }
+            "--input" | "-i" => {
+                let value = args.next().ok_or("--input requires a value")?;
+                input = Some(value.clone());
+            }
+            "--format" | "-f" if !bench => {
+                let value = args.next().ok_or("--format requires a value")?;
+                format = match value.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    _ => return Err(format!("invalid format: {}", value)),
+                };
+            }
+            "--trace" | "-t" => {
+                let value = args.next().ok_or("--trace requires a value")?;
+                trace = Some(value.parse()?);
+            }
+            "--animate" if !bench => animate = true,
+            "--parallel" if !bench => {
+                let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+                jobs = jobs.or(Some(threads));
+            }
+            "--jobs" | "-j" if !bench => {
+                let value = args.next().ok_or("--jobs requires a value")?;
+                match value.parse() {
+                    Ok(n) if n > 0 => jobs = Some(n),
+                    _ => return Err(format!("invalid number of jobs: {}", value)),
+                }
+            }
+            "--delay" if !bench => {
+                let value = args.next().ok_or("--delay requires a value")?;
+                delay = value
+                    .parse()
+                    .map(Duration::from_millis)
+                    .map_err(|_| format!("invalid delay: {}", value))?;
+            }
+            "--runs" | "-n" if bench => {
+                let value = args.next().ok_or("--runs requires a value")?;
+                match value.parse() {
+                    Ok(n) if n > 0 => runs = n,
+                    _ => return Err(format!("invalid number of runs: {}", value)),
+                }
+            }
+            "--output" | "-o" if bench => {
+                let value = args.next().ok_or("--output requires a value")?;
+                if !value.ends_with(".csv") && !value.ends_with(".json") {
+                    return Err(format!("unknown output format: {}", value));
+                }
+                output = Some(value.clone());
+            }
+            "all" => days = Some(DAYS.iter().collect()),
+            flag if flag.starts_with('-') => return Err(format!("unknown option: {}", flag)),
+            day => {
+                let day = day
+                    .parse()
+                    .ok()
+                    .and_then(get_day)
+                    .ok_or_else(|| format!("invalid day: {}", day))?;
+                days = Some(vec![day]);
+            }
+        }
+    }
+
+    let days: Vec<_> = days.ok_or("missing day")?;
+    if input.is_some() && days.len() > 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if jobs.is_some() && (animate || format == Format::Json) {
+        return Err("--parallel can't be combined with --animate or --format json".to_string());
+    }
+    if animate && (days.len() > 1 || !animation::ANIMATED_DAYS.contains(&days[0].day)) {
+        return Err("--animate can only be used with day 11, 20 or 25".to_string());
+    }
+
+    Ok(Options {
+        days,
+        part,
+        input,
+        format,
+        animate,
+        jobs,
+        trace,
+        delay,
+        runs,
+        output,
+    })
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().is_some_and(|arg| arg == "check") {
+        check(&args[1..]);
+        return;
+    }
+    if args.first().is_some_and(|arg| arg == "fetch") {
+        if let Err(err) = fetch_inputs(&args[1..]) {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.first().is_some_and(|arg| arg == "gen") {
+        if let Err(err) = generate(&args[1..]) {
+            eprintln!("error: {}\n{}", err, USAGE);
+            std::process::exit(2);
+        }
+        return;
+    }
+
+    let bench = args.first().is_some_and(|arg| arg == "bench");
+    let args = if bench { &args[1..] } else { &args[..] };
+    let options = match parse_args(args, bench) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if let Some(filter) = options.trace.clone() {
+        trace::init(filter);
+    } else if let Err(err) = trace::init_from_env() {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    }
+    if bench {
+        run_bench(&options);
+        return;
+    }
+    if options.animate {
+        animate(&options);
+        return;
+    }
+    if let Some(threads) = options.jobs {
+        run_parallel(&options, threads);
+        return;
+    }
+
+    let table = options.format == Format::Table;
+    if table {
+        println!(
+            "{:>3}  {:>4}  {:<20}  {:>10}",
+            "Day", "Part", "Answer", "Time"
+        );
+        println!("{:-<3}  {:-<4}  {:-<20}  {:->10}", "", "", "", "");
+    }
+
+    let mut total = Duration::ZERO;
+    let mut json_objects = Vec::new();
+    let mut failed = false;
+    for day in &options.days {
+        let source = InputSource::new(day.day, options.input.as_deref());
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: failed to read {}: {}", source, err);
+                std::process::exit(1);
+            }
+        };
+
+        let parsed = match day.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprint!("{}", err.render(&input, &source.to_string()));
+                failed = true;
+                continue;
+            }
+        };
+        for part in 1..=day.parts {
+            if options.part.is_some_and(|p| p != part) {
+                continue;
+            }
+
+            let start = Instant::now();
+            let answer = day.solve(parsed.as_ref(), part);
+            let elapsed = start.elapsed();
+            total += elapsed;
+
+            if table {
+                print_row(day.day, part, &answer, elapsed);
+            } else {
+                json_objects.push(format!(
+                    "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"input\": {}}}",
+                    day.day,
+                    part,
+                    json::string(&answer),
+                    elapsed.as_nanos(),
+                    json::string(&source.to_string())
+                ));
+            }
+        }
+    }
+
+    if !table {
+        print!("{}", json::array(&json_objects));
+    } else if options.days.len() > 1 {
+        println!("{:-<3}  {:-<4}  {:-<20}  {:->10}", "", "", "", "");
+        println!("{:>3}  {:>4}  {:<20}  {:>10.2?}", "", "", "Total", total);
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Times parsing and each part of the selected days over several runs.
+fn run_bench(options: &Options) {
+    println!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Runs", "Min", "Median", "P95"
+    );
+    println!(
+        "{:-<3}  {:-<5}  {:-<4}  {:->10}  {:->10}  {:->10}",
+        "", "", "", "", "", ""
+    );
+
+    let mut results = Vec::new();
+    let mut failed = false;
+    for day in &options.days {
+        let source = InputSource::new(day.day, options.input.as_deref());
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: failed to read {}: {}", source, err);
+                std::process::exit(1);
+            }
+        };
+
+        match bench::bench(day, &input, options.runs) {
+            Ok(day_results) => {
+                for r in &day_results {
+                    println!(
+                        "{:>3}  {:<5}  {:>4}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+                        r.day, r.phase, r.runs, r.stats.min, r.stats.median, r.stats.p95
+                    );
+                }
+                results.extend(day_results);
+            }
+            Err(err) => {
+                eprint!("{}", err.render(&input, &source.to_string()));
+                failed = true;
+            }
+        }
+    }
+
+    if let Some(output) = &options.output {
+        let contents = if output.ends_with(".json") {
+            bench::to_json(&results)
+        } else {
+            bench::to_csv(&results)
+        };
+        if let Err(err) = std::fs::write(output, contents) {
+            eprintln!("error: failed to write {}: {}", output, err);
+            failed = true;
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Solves all parts of the selected days concurrently, then prints them sorted
+/// by day. Solvers that fail or panic are reported without stopping the others.
+fn run_parallel(options: &Options, threads: usize) {
+    let inputs = options
+        .days
+        .iter()
+        .map(|day| {
+            let source = InputSource::new(day.day, options.input.as_deref());
+            source
+                .read()
+                .map_err(|err| format!("failed to read {}: {}", source, err))
+        })
+        .collect::<Vec<_>>();
+    let jobs = options
+        .days
+        .iter()
+        .zip(&inputs)
+        .flat_map(|(day, input)| {
+            (1..=day.parts)
+                .filter(|part| options.part.is_none_or(|p| p == *part))
+                .map(|part| parallel::Job {
+                    day,
+                    part,
+                    input: input.as_deref().map_err(String::as_str),
+                })
+        })
+        .collect::<Vec<_>>();
+
+    // Panics are reported in the summary instead
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let results = parallel::run(&jobs, threads);
+    let wall_clock = start.elapsed();
+    std::panic::set_hook(hook);
+
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>10}",
+        "Day", "Part", "Answer", "Time"
+    );
+    println!("{:-<3}  {:-<4}  {:-<20}  {:->10}", "", "", "", "");
+    let mut failed = 0;
+    for r in &results {
+        match &r.outcome {
+            Outcome::Answer(answer) => print_row(r.day, r.part, answer, r.elapsed),
+            Outcome::ReadError(reason) => {
+                failed += 1;
+                println!("{:>3}  {:>4}  error: {}", r.day, r.part, reason);
+            }
+            Outcome::ParseError(err) => {
+                failed += 1;
+                println!("{:>3}  {:>4}  error: {}", r.day, r.part, err);
+            }
+            Outcome::Panic(message) => {
+                failed += 1;
+                println!("{:>3}  {:>4}  panicked: {}", r.day, r.part, message);
+            }
+        }
+    }
+    let total = results.iter().map(|r| r.elapsed).sum::<Duration>();
+    println!("{:-<3}  {:-<4}  {:-<20}  {:->10}", "", "", "", "");
+    println!("{:>3}  {:>4}  {:<20}  {:>10.2?}", "", "", "Total", total);
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>10.2?}",
+        "", "", "Wall clock", wall_clock
+    );
+
+    if failed > 0 {
+        println!("{} of {} parts failed", failed, results.len());
+        std::process::exit(1);
+    }
+}
+
+/// Plays the simulation of the selected day in the terminal.
+fn animate(options: &Options) {
+    let day = options.days[0];
+    let source = InputSource::new(day.day, options.input.as_deref());
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", source, err);
+            std::process::exit(1);
+        }
+    };
+
+    let frames = match animation::frames(day.day, &input) {
+        Some(Ok(frames)) => frames,
+        Some(Err(err)) => {
+            eprint!("{}", err.render(&input, &source.to_string()));
+            std::process::exit(1);
+        }
+        None => unreachable!("checked by parse_args"),
+    };
+    if let Err(err) = animation::play(frames, options.delay, &mut std::io::stdout().lock()) {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+/// Prints a random input for a day.
+fn generate(args: &[String]) -> Result<(), String> {
+    let mut generator = None;
+    let mut seed = None;
+    let mut size = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" | "-s" => {
+                let value = args.next().ok_or("--seed requires a value")?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {}", value))?,
+                );
+            }
+            "--size" => {
+                let value = args.next().ok_or("--size requires a value")?;
+                size = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid size: {}", value))?,
+                );
+            }
+            flag if flag.starts_with('-') => return Err(format!("unknown option: {}", flag)),
+            day => {
+                generator = Some(
+                    day.parse()
+                        .ok()
+                        .and_then(get_generator)
+                        .ok_or_else(|| format!("invalid day: {}", day))?,
+                );
+            }
+        }
+    }
+
+    let generator = generator.ok_or("missing day")?;
+    // Without a seed every run generates a new input
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    });
+    print!(
+        "{}",
+        generator.generate(seed, size.unwrap_or(generator.default_size))
+    );
+    Ok(())
+}
+
+/// Downloads the inputs of the given days that are not in `inputs/` yet.
+fn fetch_inputs(args: &[String]) -> Result<(), String> {
+    let mut days = None;
+    let mut config_path = fetch::DEFAULT_CONFIG_PATH;
+    let mut base_url = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" | "-c" => config_path = args.next().ok_or("--config requires a value")?,
+            "--base-url" => base_url = Some(args.next().ok_or("--base-url requires a value")?),
+            "all" => days = Some((1..=25).collect()),
+            flag if flag.starts_with('-') => return Err(format!("unknown option: {}", flag)),
+            day => match day.parse() {
+                Ok(day @ 1..=25) => days = Some(vec![day]),
+                _ => return Err(format!("invalid day: {}", day)),
+            },
+        }
+    }
+    let days: Vec<u8> = days.ok_or("missing day")?;
+
+    let mut config = fetch::Config::read(config_path)?;
+    if let Some(base_url) = base_url {
+        config.base_url = base_url.trim_end_matches('/').to_string();
+    }
+    let dir = std::path::Path::new(input::INPUTS_DIR);
+    for day in days {
+        match fetch::fetch(day, &config, &fetch::Curl, dir)? {
+            Fetched::Cached(path) => println!("{}  cached", path.display()),
+            Fetched::Downloaded(path) => println!("{}  downloaded", path.display()),
+        }
+    }
+    Ok(())
+}
+
+/// Solves every recorded input again, reporting answers that changed.
+fn check(args: &[String]) {
+    let path = match args {
+        [] => answers::DEFAULT_PATH,
+        [flag, path] if flag == "--answers" || flag == "-a" => path,
+        _ => {
+            eprintln!("error: invalid arguments\n{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+    let recorded = match answers::read(path) {
+        Ok(recorded) => recorded,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let (mut checked, mut failed) = (0, 0);
+    for r in &recorded {
+        let checks = match r.check() {
+            Ok(checks) => checks,
+            Err(err) => {
+                println!("{:<24}  error: {}", r.input.display(), err);
+                failed += 1;
+                continue;
+            }
+        };
+        for check in checks {
+            checked += 1;
+            if check.is_match() {
+                println!("{:<24}  part {}  ok", r.input.display(), check.part);
+            } else {
+                failed += 1;
+                println!(
+                    "{:<24}  part {}  MISMATCH: expected {:?}, got {:?}",
+                    r.input.display(),
+                    check.part,
+                    check.expected,
+                    check.actual
+                );
+            }
+        }
+    }
+
+    println!("{} answers checked, {} failed", checked, failed);
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn print_row(day: u8, part: usize, answer: &str, elapsed: Duration) {
+    if answer.contains('\n') {
+        // Multi-line answers (e.g. day 13 letters) are printed below the row
+        println!("{:>3}  {:>4}  {:<20}  {:>10.2?}", day, part, "", elapsed);
+        for line in answer.lines() {
+            println!("{:>3}  {:>4}  {}", "", "", line);
+        }
+    } else {
+        println!(
+            "{:>3}  {:>4}  {:<20}  {:>10.2?}",
+            day, part, answer, elapsed
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::{cmp::Ordering, fmt::Display};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Fixed number of bits, bit 0 being the least significant one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// `len` bits, all unset.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bits, 64 per word from the least significant ones.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {} out of {}", i, self.len);
+        self.words[i / WORD_BITS] >> (i % WORD_BITS) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {} out of {}", i, self.len);
+        let mask = 1 << (i % WORD_BITS);
+        match value {
+            true => self.words[i / WORD_BITS] |= mask,
+            false => self.words[i / WORD_BITS] &= !mask,
+        }
+    }
+
+    /// Every bit flipped.
+    pub fn complement(&self) -> Self {
+        let mut complement = Self::new(self.len);
+        for (word, flipped) in self.words.iter().zip(&mut complement.words) {
+            *flipped = !word;
+        }
+        complement.clear_unused();
+        complement
+    }
+
+    /// Value of the bits, `None` if it doesn't fit in a `usize`.
+    pub fn to_usize(&self) -> Option<usize> {
+        if self.words.iter().skip(1).any(|word| *word != 0) {
+            return None;
+        }
+        self.words
+            .first()
+            .map_or(Some(0), |word| usize::try_from(*word).ok())
+    }
+
+    /// Keeps the bits past `len` unset, so that equal sets have equal words.
+    fn clear_unused(&mut self) {
+        if !self.len.is_multiple_of(WORD_BITS) {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << (self.len % WORD_BITS)) - 1;
+            }
+        }
+    }
+}
+
+/// Sets of the same length compare like the numbers they represent.
+impl Ord for BitSet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.len
+            .cmp(&other.len)
+            .then_with(|| self.words.iter().rev().cmp(other.words.iter().rev()))
+    }
+}
+
+impl PartialOrd for BitSet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Binary digits, most significant first.
+impl Display for BitSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in (0..self.len).rev() {
+            write!(f, "{}", if self.get(i) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits() {
+        let mut bits = BitSet::new(70);
+        bits.set(0, true);
+        bits.set(2, true);
+        assert_eq!(bits.to_usize(), Some(0b101));
+        assert!(bits.get(2) && !bits.get(1));
+
+        bits.set(69, true);
+        assert_eq!(bits.to_usize(), None);
+        assert_eq!(bits.to_string(), format!("1{}101", "0".repeat(66)));
+        assert!(bits > BitSet::new(70));
+
+        let complement = bits.complement();
+        assert_eq!(complement.to_string(), format!("0{}010", "1".repeat(66)));
+        assert_eq!(complement.complement(), bits);
+
+        bits.set(69, false);
+        assert_eq!(bits.to_usize(), Some(0b101));
+        assert_eq!(BitSet::new(0).to_usize(), Some(0));
+    }
+}
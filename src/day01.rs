@@ -0,0 +1,272 @@
+use std::{collections::VecDeque, fmt::Display, ops::RangeInclusive};
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(depths: &Self::Input) -> Self::Answer1 {
+        count_increases(depths)
+    }
+
+    fn part2(depths: &Self::Input) -> Self::Answer2 {
+        count_increases_three_measurements_window(depths)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let parser = Parser::new(Day01::DAY, input);
+    input.lines().map(|line| parser.number(line)).collect()
+}
+
+pub fn count_increases(depths: &[usize]) -> usize {
+    count_increases_with_window(depths.iter().copied(), 1)
+}
+
+pub fn count_increases_three_measurements_window(depths: &[usize]) -> usize {
+    count_increases_with_window(depths.iter().copied(), 3)
+}
+
+/// Number of times the sum of `n` consecutive depths is larger than the sum
+/// of the previous `n`.
+///
+/// Two consecutive windows share all but their first and last depths, so only
+/// those are compared. Only the last `n` depths are kept, so `depths` can be
+/// streamed from a log of any size.
+pub fn count_increases_with_window(depths: impl IntoIterator<Item = usize>, n: usize) -> usize {
+    assert!(n > 0, "windows must not be empty");
+    let mut window = VecDeque::with_capacity(n + 1);
+    let mut count = 0;
+    for depth in depths {
+        window.push_back(depth);
+        if window.len() > n && window.pop_front().is_some_and(|first| depth > first) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Statistic of a depth report, with the depths it was found at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence<T> {
+    pub value: T,
+    pub range: RangeInclusive<usize>,
+}
+
+impl<T: PartialOrd> Occurrence<T> {
+    /// Keeps the first occurrence on ties.
+    fn keep_max(current: &mut Option<Self>, value: T, range: RangeInclusive<usize>) {
+        if current.as_ref().is_none_or(|c| value > c.value) {
+            *current = Some(Self { value, range });
+        }
+    }
+
+    fn keep_min(current: &mut Option<Self>, value: T, range: RangeInclusive<usize>) {
+        if current.as_ref().is_none_or(|c| value < c.value) {
+            *current = Some(Self { value, range });
+        }
+    }
+}
+
+/// Statistics of a list of depths, computed in a single pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SonarReport {
+    pub measurements: usize,
+    /// Number of depths in the longest strictly increasing run
+    pub longest_increasing_run: Option<Occurrence<usize>>,
+    /// Largest decrease between two consecutive depths
+    pub largest_drop: Option<Occurrence<usize>>,
+    /// Number of depths in the moving averages
+    pub window: usize,
+    pub shallowest_average: Option<Occurrence<f64>>,
+    pub deepest_average: Option<Occurrence<f64>>,
+}
+
+impl SonarReport {
+    /// Report with moving averages of 3 depths, like the sliding windows of
+    /// part 2.
+    pub fn new(depths: impl IntoIterator<Item = usize>) -> Self {
+        Self::with_window(depths, 3)
+    }
+
+    pub fn with_window(depths: impl IntoIterator<Item = usize>, window: usize) -> Self {
+        assert!(window > 0, "windows must not be empty");
+        let mut report = Self {
+            measurements: 0,
+            longest_increasing_run: None,
+            largest_drop: None,
+            window,
+            shallowest_average: None,
+            deepest_average: None,
+        };
+
+        let mut previous = None;
+        let mut run_start = 0;
+        let mut last_depths = VecDeque::with_capacity(window + 1);
+        let mut sum = 0;
+        for (i, depth) in depths.into_iter().enumerate() {
+            report.measurements += 1;
+
+            match previous {
+                Some(previous) if depth > previous => (),
+                _ => run_start = i,
+            }
+            Occurrence::keep_max(
+                &mut report.longest_increasing_run,
+                i - run_start + 1,
+                run_start..=i,
+            );
+
+            if let Some(previous) = previous.filter(|previous| *previous > depth) {
+                Occurrence::keep_max(&mut report.largest_drop, previous - depth, i - 1..=i);
+            }
+            previous = Some(depth);
+
+            last_depths.push_back(depth);
+            sum += depth;
+            if last_depths.len() > window {
+                sum -= last_depths.pop_front().unwrap();
+            }
+            if last_depths.len() == window {
+                let average = sum as f64 / window as f64;
+                let range = i + 1 - window..=i;
+                Occurrence::keep_min(&mut report.shallowest_average, average, range.clone());
+                Occurrence::keep_max(&mut report.deepest_average, average, range);
+            }
+        }
+
+        report
+    }
+}
+
+impl Display for SonarReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Measurements: {}", self.measurements)?;
+        if let Some(run) = &self.longest_increasing_run {
+            writeln!(
+                f,
+                "Longest increasing run: {} depths, at {}..={}",
+                run.value,
+                run.range.start(),
+                run.range.end()
+            )?;
+        }
+        match &self.largest_drop {
+            Some(drop) => writeln!(
+                f,
+                "Largest drop: {}, at {}..={}",
+                drop.value,
+                drop.range.start(),
+                drop.range.end()
+            )?,
+            None => writeln!(f, "Largest drop: none")?,
+        }
+        for (name, average) in [
+            ("Shallowest", &self.shallowest_average),
+            ("Deepest", &self.deepest_average),
+        ] {
+            if let Some(average) = average {
+                writeln!(
+                    f,
+                    "{} average of {}: {:.2}, at {}..={}",
+                    name,
+                    self.window,
+                    average.value,
+                    average.range.start(),
+                    average.range.end()
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn example() {
+        let depths = parse_input(INPUT_EXAMPLE).unwrap();
+
+        assert_eq!(depths, [199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+        // Part 1
+        assert_eq!(count_increases(&depths), 7);
+
+        // Part 2
+        assert_eq!(count_increases_three_measurements_window(&depths), 5);
+    }
+
+    #[test]
+    fn windows() {
+        let depths = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!(count_increases_with_window(depths.clone(), 1), 7);
+        assert_eq!(count_increases_with_window(depths.clone(), 3), 5);
+        assert_eq!(count_increases_with_window(depths.clone(), 8), 2);
+        assert_eq!(count_increases_with_window(depths, 10), 0);
+
+        // Streamed sawtooth 0, 1, 2, 3, 4, 0, 1..., where only a 4 is deeper
+        // than the depth 4 positions before it
+        let stream = (0..1_000_000).map(|i| i % 5);
+        assert_eq!(count_increases_with_window(stream, 4), 200_000);
+    }
+
+    #[test]
+    fn report() {
+        let depths = parse_input(INPUT_EXAMPLE).unwrap();
+        let report = SonarReport::new(depths);
+        assert_eq!(report.measurements, 10);
+        assert_eq!(
+            report.longest_increasing_run,
+            Some(Occurrence {
+                value: 4,
+                range: 0..=3
+            })
+        );
+        assert_eq!(
+            report.largest_drop,
+            Some(Occurrence {
+                value: 10,
+                range: 3..=4
+            })
+        );
+        assert_eq!(
+            report.to_string(),
+            "Measurements: 10
+Longest increasing run: 4 depths, at 0..=3
+Largest drop: 10, at 3..=4
+Shallowest average of 3: 202.33, at 0..=2
+Deepest average of 3: 264.00, at 7..=9
+"
+        );
+
+        let report = SonarReport::new([3, 2]);
+        assert_eq!(report.shallowest_average, None);
+        assert_eq!(report.longest_increasing_run.unwrap().range, 0..=0);
+    }
+}
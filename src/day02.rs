@@ -0,0 +1,731 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+};
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(commands: &Self::Input) -> Self::Answer1 {
+        let mut submarine = Submarine::default();
+        submarine.navigate(commands);
+        submarine.horizontal_position * submarine.depth
+    }
+
+    fn part2(commands: &Self::Input) -> Self::Answer2 {
+        let mut submarine = SubmarineComplex::default();
+        submarine.navigate(commands);
+        submarine.horizontal_position * submarine.depth
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Command {
+    Forward(isize),
+    Down(isize),
+    Up(isize),
+    /// Quarter turns, only steering [`Submarine3d`]. Scripts can have them,
+    /// not the puzzle's input.
+    Left(u8),
+    Right(u8),
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Forward(v) => write!(f, "forward {}", v),
+            Command::Down(v) => write!(f, "down {}", v),
+            Command::Up(v) => write!(f, "up {}", v),
+            Command::Left(v) => write!(f, "left {}", 90 * *v as usize),
+            Command::Right(v) => write!(f, "right {}", 90 * *v as usize),
+        }
+    }
+}
+
+/// State of a submarine between two commands. Models without an aim or a
+/// heading keep them at 0.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Waypoint {
+    pub horizontal_position: isize,
+    /// Position to the left of the horizontal axis
+    pub lateral_position: isize,
+    pub depth: isize,
+    pub aim: isize,
+    /// Degrees counterclockwise from the horizontal axis, in `0..360`
+    pub heading: isize,
+}
+
+/// Waypoints of a submarine, from before its first command to after its last.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Trajectory {
+    pub steps: Vec<(Option<Command>, Waypoint)>,
+}
+
+impl Trajectory {
+    /// One row per step, the first one without a command.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("step,command,horizontal_position,lateral_position,depth,aim,heading\n");
+        for (i, (command, waypoint)) in self.steps.iter().enumerate() {
+            let command = command.map(|c| c.to_string()).unwrap_or_default();
+            writeln!(
+                csv,
+                "{},{},{},{},{},{},{}",
+                i,
+                command,
+                waypoint.horizontal_position,
+                waypoint.lateral_position,
+                waypoint.depth,
+                waypoint.aim,
+                waypoint.heading
+            )
+            .unwrap();
+        }
+        csv
+    }
+}
+
+pub trait Navigation {
+    fn execute(&mut self, command: &Command);
+
+    fn waypoint(&self) -> Waypoint;
+
+    fn navigate(&mut self, commands: &[Command]) {
+        for c in commands {
+            self.execute(c);
+        }
+    }
+
+    /// Like [`Navigation::navigate`], also recording the waypoint after each
+    /// command.
+    fn navigate_recording(&mut self, commands: &[Command]) -> Trajectory {
+        let mut steps = vec![(None, self.waypoint())];
+        for c in commands {
+            self.execute(c);
+            steps.push((Some(*c), self.waypoint()));
+        }
+        Trajectory { steps }
+    }
+}
+
+#[derive(Default)]
+pub struct Submarine {
+    pub horizontal_position: isize,
+    pub depth: isize,
+}
+
+impl Navigation for Submarine {
+    fn execute(&mut self, command: &Command) {
+        match command {
+            Command::Forward(v) => self.horizontal_position += v,
+            Command::Up(v) => self.depth -= v,
+            Command::Down(v) => self.depth += v,
+            Command::Left(_) | Command::Right(_) => {}
+        }
+    }
+
+    fn waypoint(&self) -> Waypoint {
+        Waypoint {
+            horizontal_position: self.horizontal_position,
+            depth: self.depth,
+            ..Waypoint::default()
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SubmarineComplex {
+    pub horizontal_position: isize,
+    pub depth: isize,
+    pub aim: isize,
+}
+
+impl Navigation for SubmarineComplex {
+    fn execute(&mut self, command: &Command) {
+        match command {
+            Command::Forward(v) => {
+                self.horizontal_position += v;
+                self.depth += self.aim * v;
+            }
+            Command::Up(v) => self.aim -= v,
+            Command::Down(v) => self.aim += v,
+            Command::Left(_) | Command::Right(_) => {}
+        }
+    }
+
+    fn waypoint(&self) -> Waypoint {
+        Waypoint {
+            horizontal_position: self.horizontal_position,
+            depth: self.depth,
+            aim: self.aim,
+            ..Waypoint::default()
+        }
+    }
+}
+
+/// Direction of a [`Submarine3d`] in the horizontal plane.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Heading {
+    /// Along the horizontal axis
+    #[default]
+    East,
+    /// Along the lateral axis
+    North,
+    West,
+    South,
+}
+
+impl Heading {
+    /// Heading after `quarter_turns` counterclockwise.
+    pub fn turn(self, quarter_turns: u8) -> Self {
+        match (self as u8 + quarter_turns % 4) % 4 {
+            0 => Self::East,
+            1 => Self::North,
+            2 => Self::West,
+            _ => Self::South,
+        }
+    }
+
+    /// Degrees counterclockwise from the horizontal axis.
+    pub fn degrees(self) -> isize {
+        90 * self as isize
+    }
+
+    fn direction(self) -> (isize, isize) {
+        match self {
+            Self::East => (1, 0),
+            Self::North => (0, 1),
+            Self::West => (-1, 0),
+            Self::South => (0, -1),
+        }
+    }
+}
+
+/// Submarine with an aim that can also turn left and right, starting along
+/// the horizontal axis. It navigates like [`SubmarineComplex`] until it turns.
+#[derive(Default)]
+pub struct Submarine3d {
+    pub horizontal_position: isize,
+    pub lateral_position: isize,
+    pub depth: isize,
+    pub aim: isize,
+    pub heading: Heading,
+}
+
+impl Navigation for Submarine3d {
+    fn execute(&mut self, command: &Command) {
+        match command {
+            Command::Forward(v) => {
+                let (dx, dy) = self.heading.direction();
+                self.horizontal_position += dx * v;
+                self.lateral_position += dy * v;
+                self.depth += self.aim * v;
+            }
+            Command::Up(v) => self.aim -= v,
+            Command::Down(v) => self.aim += v,
+            Command::Left(v) => self.heading = self.heading.turn(*v),
+            Command::Right(v) => self.heading = self.heading.turn(4 - v % 4),
+        }
+    }
+
+    fn waypoint(&self) -> Waypoint {
+        Waypoint {
+            horizontal_position: self.horizontal_position,
+            lateral_position: self.lateral_position,
+            depth: self.depth,
+            aim: self.aim,
+            heading: self.heading.degrees(),
+        }
+    }
+}
+
+/// Final waypoint of every navigation model after `commands`, to compare them.
+pub fn compare_models(commands: &[Command]) -> Vec<(&'static str, Waypoint)> {
+    let models: [(&str, Box<dyn Navigation>); 3] = [
+        ("submarine", Box::new(Submarine::default())),
+        ("complex", Box::new(SubmarineComplex::default())),
+        ("3d", Box::new(Submarine3d::default())),
+    ];
+    models
+        .into_iter()
+        .map(|(name, mut model)| {
+            model.navigate(commands);
+            (name, model.waypoint())
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    Parser::new(Day02::DAY, input).lines(input)
+}
+
+impl std::str::FromStr for Command {
+    type Err = ParseError;
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new(Day02::DAY, command);
+        let (direction, value) = parser.split_once(command, " ")?;
+        let value = parser.number(value)?;
+        match direction {
+            "forward" => Ok(Command::Forward(value)),
+            "down" => Ok(Command::Down(value)),
+            "up" => Ok(Command::Up(value)),
+            _ => Err(parser.error(direction, "'forward', 'down' or 'up'")),
+        }
+    }
+}
+
+/// Scripts larger than this once expanded are rejected.
+pub const MAX_SCRIPT_COMMANDS: usize = 1_000_000;
+/// Scripts with more blocks inside one another are rejected.
+pub const MAX_SCRIPT_DEPTH: usize = 64;
+
+/// Parses a navigation script into the commands it expands to. On top of the
+/// puzzle's commands, scripts can have:
+///
+/// ```text
+/// # comments, until the end of the line
+/// back 2      # moves backwards, like 'forward -2'
+/// left 90     # turns by a multiple of 90 degrees, see Submarine3d
+/// def dive    # defines a macro, called by its name from the next lines
+///   down 3
+///   forward 1
+/// end
+/// repeat 4    # repeats a block
+///   dive
+/// end
+/// ```
+pub fn parse_script(script: &str) -> Result<Vec<Command>, ParseError> {
+    let mut lines = script
+        .lines()
+        .map(|line| line.split_once('#').map_or(line, |(code, _)| code).trim())
+        .filter(|line| !line.is_empty());
+    ScriptParser {
+        parser: Parser::new(Day02::DAY, script),
+        macros: HashMap::new(),
+        last_line: script.trim_end(),
+    }
+    .block(&mut lines, 0)
+}
+
+struct ScriptParser<'a> {
+    parser: Parser<'a>,
+    macros: HashMap<&'a str, Vec<Command>>,
+    /// Where a missing `end` is reported
+    last_line: &'a str,
+}
+
+impl<'a> ScriptParser<'a> {
+    /// Expands the lines up to the `end` of the current block, or up to the
+    /// end of the script at the top level (`depth` 0).
+    fn block(
+        &mut self,
+        lines: &mut impl Iterator<Item = &'a str>,
+        depth: usize,
+    ) -> Result<Vec<Command>, ParseError> {
+        let nested = depth > 0;
+        let mut commands = Vec::new();
+        while let Some(line) = lines.next() {
+            let (keyword, argument) = match line.split_once(' ') {
+                Some((keyword, argument)) => (keyword, Some(argument.trim_start())),
+                None => (line, None),
+            };
+            if matches!(keyword, "repeat" | "def") && depth == MAX_SCRIPT_DEPTH {
+                let expected = format!("at most {} nested blocks", MAX_SCRIPT_DEPTH);
+                return Err(self.parser.error(keyword, expected));
+            }
+            match (keyword, argument) {
+                ("end", None) if nested => return Ok(commands),
+                ("end", None) => return Err(self.parser.error(keyword, "'repeat' or 'def' block")),
+                ("repeat", Some(count)) => {
+                    let count: usize = self.parser.number(count)?;
+                    let body = self.block(lines, depth + 1)?;
+                    let len = body
+                        .len()
+                        .checked_mul(count)
+                        .map(|len| len + commands.len());
+                    if len.is_none_or(|len| len > MAX_SCRIPT_COMMANDS) {
+                        return Err(self.too_large(line));
+                    }
+                    for _ in 0..count {
+                        commands.extend_from_slice(&body);
+                    }
+                }
+                ("def", Some(name)) => {
+                    if nested {
+                        return Err(self.parser.error(keyword, "'def' outside of blocks"));
+                    }
+                    if !is_macro_name(name) || self.macros.contains_key(name) {
+                        return Err(self.parser.error(name, "new macro name"));
+                    }
+                    let body = self.block(lines, depth + 1)?;
+                    self.macros.insert(name, body);
+                }
+                ("back", Some(distance)) => {
+                    let distance = distance
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|distance| isize::try_from(distance).ok())
+                        .ok_or_else(|| self.parser.error(distance, "non-negative distance"))?;
+                    commands.push(Command::Forward(-distance));
+                }
+                ("forward" | "down" | "up", Some(_)) => commands.push(self.parser.parse(line)?),
+                ("left" | "right", Some(angle)) => {
+                    let degrees: isize = self.parser.number(angle)?;
+                    if degrees % 90 != 0 {
+                        return Err(self.parser.error(angle, "multiple of 90"));
+                    }
+                    let quarter_turns = (degrees / 90).rem_euclid(4) as u8;
+                    commands.push(match keyword {
+                        "left" => Command::Left(quarter_turns),
+                        _ => Command::Right(quarter_turns),
+                    });
+                }
+                (name, None) if self.macros.contains_key(name) => {
+                    commands.extend_from_slice(&self.macros[name]);
+                }
+                (name, Some(argument)) if self.macros.contains_key(name) => {
+                    return Err(self.parser.error(argument, "end of line"));
+                }
+                (
+                    "repeat" | "def" | "back" | "forward" | "down" | "up" | "left" | "right",
+                    None,
+                ) => {
+                    return Err(self.parser.error_after(keyword, "' '"));
+                }
+                (keyword, _) => {
+                    return Err(self.parser.error(keyword, "command or defined macro"));
+                }
+            }
+            if commands.len() > MAX_SCRIPT_COMMANDS {
+                return Err(self.too_large(line));
+            }
+        }
+        match nested {
+            true => Err(self.parser.error_after(self.last_line, "'end'")),
+            false => Ok(commands),
+        }
+    }
+
+    fn too_large(&self, line: &str) -> ParseError {
+        let expected = format!("at most {} commands", MAX_SCRIPT_COMMANDS);
+        self.parser.error(line, expected)
+    }
+}
+
+fn is_macro_name(name: &str) -> bool {
+    const KEYWORDS: [&str; 9] = [
+        "forward", "down", "up", "left", "right", "back", "repeat", "def", "end",
+    ];
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn example() {
+        let commands = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!(
+            commands,
+            [
+                Command::Forward(5),
+                Command::Down(5),
+                Command::Forward(8),
+                Command::Up(3),
+                Command::Down(8),
+                Command::Forward(2)
+            ]
+        );
+
+        // Part 1
+        let mut submarine = Submarine::default();
+        submarine.navigate(&commands);
+        assert_eq!(submarine.horizontal_position, 15);
+        assert_eq!(submarine.depth, 10);
+        assert_eq!(submarine.horizontal_position * submarine.depth, 150);
+
+        // Part 2
+        let mut submarine = SubmarineComplex::default();
+        submarine.navigate(&commands);
+        assert_eq!(submarine.horizontal_position, 15);
+        assert_eq!(submarine.depth, 60);
+        assert_eq!(submarine.horizontal_position * submarine.depth, 900);
+    }
+
+    #[test]
+    fn trajectory() {
+        let commands = parse_input(INPUT_EXAMPLE).unwrap();
+        let trajectory = SubmarineComplex::default().navigate_recording(&commands);
+        assert_eq!(trajectory.steps.len(), 7);
+        assert_eq!(
+            trajectory.steps[3],
+            (
+                Some(Command::Forward(8)),
+                Waypoint {
+                    horizontal_position: 13,
+                    depth: 40,
+                    aim: 5,
+                    ..Waypoint::default()
+                }
+            )
+        );
+        assert_eq!(
+            trajectory.to_csv(),
+            "step,command,horizontal_position,lateral_position,depth,aim,heading
+0,,0,0,0,0,0
+1,forward 5,5,0,0,0,0
+2,down 5,5,0,0,5,0
+3,forward 8,13,0,40,5,0
+4,up 3,13,0,40,2,0
+5,down 8,13,0,40,10,0
+6,forward 2,15,0,60,10,0
+"
+        );
+
+        let mut submarine = Submarine::default();
+        let trajectory = submarine.navigate_recording(&commands);
+        assert_eq!(trajectory.steps.last().unwrap().1, submarine.waypoint());
+    }
+
+    #[test]
+    fn models() {
+        let commands = parse_input(INPUT_EXAMPLE).unwrap();
+        let waypoints = compare_models(&commands);
+        assert_eq!(waypoints[2].1, waypoints[1].1);
+
+        // Same route with a turn before the last forward, which the 2D models
+        // ignore
+        let mut commands = commands;
+        commands.insert(5, Command::Left(1));
+        let waypoints = compare_models(&commands);
+        let names = waypoints.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        assert_eq!(names, ["submarine", "complex", "3d"]);
+        assert_eq!(
+            (waypoints[0].1.horizontal_position, waypoints[0].1.depth),
+            (15, 10)
+        );
+        assert_eq!(
+            (waypoints[1].1.horizontal_position, waypoints[1].1.depth),
+            (15, 60)
+        );
+        assert_eq!(
+            waypoints[2].1,
+            Waypoint {
+                horizontal_position: 13,
+                lateral_position: 2,
+                depth: 60,
+                aim: 10,
+                heading: 90
+            }
+        );
+
+        let mut submarine = Submarine3d::default();
+        submarine.navigate(&[Command::Right(1), Command::Forward(3), Command::Left(3)]);
+        assert_eq!(submarine.lateral_position, -3);
+        assert_eq!(submarine.heading, Heading::West);
+        submarine.navigate(&[Command::Left(255), Command::Forward(1)]);
+        assert_eq!(submarine.heading, Heading::North);
+        assert_eq!(submarine.lateral_position, -2);
+    }
+
+    #[test]
+    fn script() {
+        let script = "# Same route as the example
+def dive
+  down 5 # aiming down
+  forward 8
+end
+
+forward 5
+repeat 2
+  dive
+  back 8
+end
+";
+        let commands = parse_script(script).unwrap();
+        assert_eq!(commands.len(), 7);
+        assert_eq!(commands[3], Command::Forward(-8));
+
+        let mut submarine = Submarine::default();
+        submarine.navigate(&commands);
+        assert_eq!((submarine.horizontal_position, submarine.depth), (5, 10));
+        let mut submarine = SubmarineComplex::default();
+        submarine.navigate(&commands);
+        assert_eq!((submarine.horizontal_position, submarine.depth), (5, 0));
+
+        assert_eq!(
+            parse_script("left 90\nright -450\nleft 0").unwrap(),
+            [Command::Left(1), Command::Right(3), Command::Left(0)]
+        );
+        assert_eq!(Command::Right(3).to_string(), "right 270");
+
+        assert_eq!(
+            parse_script(INPUT_EXAMPLE).unwrap(),
+            parse_input(INPUT_EXAMPLE).unwrap()
+        );
+    }
+
+    #[test]
+    fn script_errors() {
+        let error = |script: &str| {
+            let err = parse_script(script).unwrap_err();
+            (err.line, err.column, err.expected)
+        };
+        assert_eq!(
+            error(
+                "repeat 2
+  up 1
+"
+            ),
+            (2, 7, "'end'".to_string())
+        );
+        assert_eq!(
+            error(
+                "up 1
+end"
+            ),
+            (2, 1, "'repeat' or 'def' block".to_string())
+        );
+        assert_eq!(
+            error(
+                "repeat x
+end"
+            ),
+            (1, 8, "number".to_string())
+        );
+        assert_eq!(
+            error(
+                "dive
+"
+            ),
+            (1, 1, "command or defined macro".to_string())
+        );
+        assert_eq!(
+            error(
+                "forward
+"
+            ),
+            (1, 8, "' '".to_string())
+        );
+        assert_eq!(
+            error(
+                "up 1
+down x # y
+"
+            ),
+            (2, 6, "number".to_string())
+        );
+        assert_eq!(
+            error(
+                "def up
+end"
+            ),
+            (1, 5, "new macro name".to_string())
+        );
+        assert_eq!(
+            error(
+                "def a
+end
+def a
+end"
+            ),
+            (3, 5, "new macro name".to_string())
+        );
+        assert_eq!(
+            error(
+                "repeat 2
+ def a
+ end
+end"
+            ),
+            (2, 2, "'def' outside of blocks".to_string())
+        );
+        assert_eq!(
+            error(
+                "def a
+end
+a 2"
+            ),
+            (3, 3, "end of line".to_string())
+        );
+        // Macros can't call themselves, they are only defined after their
+        // body
+        assert_eq!(
+            error(
+                "def a
+ a
+end"
+            ),
+            (2, 2, "command or defined macro".to_string())
+        );
+        assert_eq!(error("left 45"), (1, 6, "multiple of 90".to_string()));
+        assert_eq!(
+            error("back -5"),
+            (1, 6, "non-negative distance".to_string())
+        );
+        assert_eq!(
+            error("back 9223372036854775808"),
+            (1, 6, "non-negative distance".to_string())
+        );
+        let deep = "repeat 1\n".repeat(200_000);
+        assert_eq!(
+            error(&deep),
+            (65, 1, "at most 64 nested blocks".to_string())
+        );
+        assert_eq!(
+            error(
+                "repeat 1000
+ repeat 1001
+  up 1
+ end
+end"
+            ),
+            (1, 1, "at most 1000000 commands".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_input("forward 5\nbackward 2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "'forward', 'down' or 'up'");
+
+        let err = parse_input("forward 5\ndown\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.expected, "' '");
+
+        let err = parse_input("forward 5\nup five\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.expected, "number");
+
+        // Turns are only for scripts
+        let err = parse_input("left 90\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "'forward', 'down' or 'up'");
+    }
+}
@@ -0,0 +1,407 @@
+use crate::{
+    bitset::BitSet,
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Report;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(report: &Self::Input) -> Self::Answer1 {
+        let (gamma_rate, epsilon_rate) = report.calculate_gamma_and_epsilon();
+        gamma_rate * epsilon_rate
+    }
+
+    fn part2(report: &Self::Input) -> Self::Answer2 {
+        let oxygen_rate = report.calculate_oxygen();
+        let co2_rate = report.calculate_co2();
+        oxygen_rate * co2_rate
+    }
+}
+
+/// Numbers of a diagnostic report, all with the same number of bits.
+#[derive(Debug)]
+pub struct Report {
+    /// In ascending order
+    values: Vec<BitSet>,
+    num_bits: usize,
+}
+
+impl std::str::FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new(Day03::DAY, s);
+        let num_bits = s
+            .lines()
+            .next()
+            .map(str::len)
+            .filter(|num_bits| *num_bits > 0)
+            .ok_or_else(|| parser.error(s, "binary number"))?;
+        let mut values = s
+            .lines()
+            .map(|line| {
+                let mut value = BitSet::new(num_bits);
+                for (i, c) in line.char_indices() {
+                    match c {
+                        '0' | '1' if i < num_bits => value.set(num_bits - 1 - i, c == '1'),
+                        '0' | '1' => break,
+                        _ => return Err(parser.error(&line[i..], "binary digit")),
+                    }
+                }
+                if line.len() != num_bits {
+                    return Err(parser.error(line, format!("line of {} bits", num_bits)));
+                }
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        values.sort_unstable();
+        Ok(Report { values, num_bits })
+    }
+}
+
+/// Number of values with each bit set, counted in a single pass.
+///
+/// The counts are bit-sliced: `planes[p]` holds bit `p` of the counts of 64
+/// columns at once, so adding a value is a ripple carry over whole words.
+pub fn count_ones<'a>(values: impl IntoIterator<Item = &'a BitSet>, num_bits: usize) -> Vec<usize> {
+    let num_words = num_bits.div_ceil(u64::BITS as usize);
+    let mut planes: Vec<Vec<u64>> = Vec::new();
+    for value in values {
+        for (w, word) in value.words().iter().enumerate() {
+            let mut carry = *word;
+            let mut p = 0;
+            while carry != 0 {
+                if p == planes.len() {
+                    planes.push(vec![0; num_words]);
+                }
+                let plane = &mut planes[p][w];
+                let next = *plane & carry;
+                *plane ^= carry;
+                carry = next;
+                p += 1;
+            }
+        }
+    }
+
+    (0..num_bits)
+        .map(|i| {
+            let (w, bit) = (i / u64::BITS as usize, i % u64::BITS as usize);
+            planes
+                .iter()
+                .enumerate()
+                .map(|(p, plane)| ((plane[w] >> bit & 1) as usize) << p)
+                .sum()
+        })
+        .collect()
+}
+
+/// Most common bit out of `len` values of which `ones` have it set, `None`
+/// on ties.
+fn most_common(ones: usize, len: usize) -> Option<bool> {
+    match (2 * ones).cmp(&len) {
+        std::cmp::Ordering::Greater => Some(true),
+        std::cmp::Ordering::Less => Some(false),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+/// Which values are kept at each step of a life support rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Commonness {
+    /// The values with the most common bit
+    Majority,
+    Minority,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+/// How a rating narrows down the values of a report, one bit at a time,
+/// until a single one is left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RatingCriterion {
+    pub keep: Commonness,
+    /// Bit of the values kept when as many have it set as unset
+    pub tie: bool,
+    pub order: BitOrder,
+}
+
+impl RatingCriterion {
+    pub const OXYGEN_GENERATOR: Self = Self {
+        keep: Commonness::Majority,
+        tie: true,
+        order: BitOrder::MsbFirst,
+    };
+    pub const CO2_SCRUBBER: Self = Self {
+        keep: Commonness::Minority,
+        tie: false,
+        order: BitOrder::MsbFirst,
+    };
+
+    /// Bit of the values kept when `ones` of the `len` remaining ones have it
+    /// set.
+    fn kept_bit(&self, ones: usize, len: usize) -> bool {
+        match (most_common(ones, len), self.keep) {
+            (None, _) => self.tie,
+            (Some(bit), Commonness::Majority) => bit,
+            (Some(bit), Commonness::Minority) => !bit,
+        }
+    }
+}
+
+impl Report {
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// The values in ascending order.
+    pub fn values(&self) -> &[BitSet] {
+        &self.values
+    }
+
+    pub fn gamma_and_epsilon(&self) -> (BitSet, BitSet) {
+        let mut gamma_rate = BitSet::new(self.num_bits);
+        let counts = count_ones(&self.values, self.num_bits);
+        for (i, ones) in counts.into_iter().enumerate() {
+            gamma_rate.set(i, most_common(ones, self.values.len()).unwrap());
+        }
+        let epsilon_rate = gamma_rate.complement();
+
+        (gamma_rate, epsilon_rate)
+    }
+
+    pub fn oxygen(&self) -> BitSet {
+        self.rating(&RatingCriterion::OXYGEN_GENERATOR)
+            .expect("no oxygen generator rating")
+    }
+
+    pub fn co2(&self) -> BitSet {
+        self.rating(&RatingCriterion::CO2_SCRUBBER)
+            .expect("no CO2 scrubber rating")
+    }
+
+    /// The value left after filtering with `criterion`, `None` if it discards
+    /// every value or several equal values are left.
+    pub fn rating(&self, criterion: &RatingCriterion) -> Option<BitSet> {
+        let rating = match criterion.order {
+            BitOrder::MsbFirst => self.filter_sorted(criterion),
+            BitOrder::LsbFirst => self.filter_scanning(criterion),
+        };
+        rating.cloned()
+    }
+
+    /// The remaining values share their most significant bits so they are
+    /// contiguous in sorted order, those with the next bit unset first: each
+    /// step is a binary search instead of a pass over the values.
+    fn filter_sorted(&self, criterion: &RatingCriterion) -> Option<&BitSet> {
+        let mut candidates = &self.values[..];
+        for bit in (0..self.num_bits).rev() {
+            if candidates.len() <= 1 {
+                break;
+            }
+            let split = candidates.partition_point(|v| !v.get(bit));
+            let (zeros, ones) = candidates.split_at(split);
+            candidates = match criterion.kept_bit(ones.len(), candidates.len()) {
+                true => ones,
+                false => zeros,
+            };
+        }
+        match candidates {
+            [rating] => Some(rating),
+            _ => None,
+        }
+    }
+
+    /// Values are only sorted by their most significant bits, so filtering
+    /// from the least significant one is a pass over the remaining values per
+    /// bit.
+    fn filter_scanning(&self, criterion: &RatingCriterion) -> Option<&BitSet> {
+        let mut candidates = self.values.iter().collect::<Vec<_>>();
+        for bit in 0..self.num_bits {
+            if candidates.len() <= 1 {
+                break;
+            }
+            let ones = candidates.iter().filter(|v| v.get(bit)).count();
+            let kept_bit = criterion.kept_bit(ones, candidates.len());
+            candidates.retain(|v| v.get(bit) == kept_bit);
+        }
+        match candidates[..] {
+            [rating] => Some(rating),
+            _ => None,
+        }
+    }
+
+    /// Like [`Report::gamma_and_epsilon`], for reports narrow enough for the
+    /// rates to fit in a `usize`.
+    pub fn calculate_gamma_and_epsilon(&self) -> (usize, usize) {
+        let (gamma_rate, epsilon_rate) = self.gamma_and_epsilon();
+        (to_usize(&gamma_rate), to_usize(&epsilon_rate))
+    }
+
+    pub fn calculate_oxygen(&self) -> usize {
+        to_usize(&self.oxygen())
+    }
+
+    pub fn calculate_co2(&self) -> usize {
+        to_usize(&self.co2())
+    }
+}
+
+fn to_usize(rate: &BitSet) -> usize {
+    rate.to_usize()
+        .unwrap_or_else(|| panic!("rate {} doesn't fit in a usize", rate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn example() {
+        let report: Report = INPUT_EXAMPLE.parse().unwrap();
+        assert_eq!(
+            report
+                .values
+                .iter()
+                .map(|v| v.to_usize().unwrap())
+                .collect::<Vec<_>>(),
+            [
+                0b00010, 0b00100, 0b00111, 0b01010, 0b01111, 0b10000, 0b10101, 0b10110, 0b10111,
+                0b11001, 0b11100, 0b11110,
+            ]
+        );
+        assert_eq!(report.num_bits, 5);
+
+        let (gamma_rate, epsilon_rate) = report.calculate_gamma_and_epsilon();
+        assert_eq!(gamma_rate, 22);
+        assert_eq!(epsilon_rate, 9);
+        assert_eq!(gamma_rate * epsilon_rate, 198);
+
+        let oxygen_rate = report.calculate_oxygen();
+        let co2_rate = report.calculate_co2();
+        assert_eq!(oxygen_rate, 23);
+        assert_eq!(co2_rate, 10);
+        assert_eq!(oxygen_rate * co2_rate, 230);
+    }
+
+    #[test]
+    fn ratings() {
+        let report: Report = INPUT_EXAMPLE.parse().unwrap();
+        let rating = |keep, tie, order| {
+            let criterion = RatingCriterion { keep, tie, order };
+            report.rating(&criterion).unwrap().to_string()
+        };
+        assert_eq!(
+            rating(Commonness::Majority, true, BitOrder::MsbFirst),
+            "10111"
+        );
+        assert_eq!(
+            rating(Commonness::Minority, false, BitOrder::MsbFirst),
+            "01010"
+        );
+        assert_eq!(
+            rating(Commonness::Minority, true, BitOrder::MsbFirst),
+            "01111"
+        );
+        assert_eq!(
+            rating(Commonness::Majority, false, BitOrder::MsbFirst),
+            "10110"
+        );
+        assert_eq!(
+            rating(Commonness::Majority, true, BitOrder::LsbFirst),
+            "11110"
+        );
+        assert_eq!(
+            rating(Commonness::Minority, false, BitOrder::LsbFirst),
+            "11001"
+        );
+
+        // Every value is discarded at the first bit
+        let report: Report = "00\n01\n".parse().unwrap();
+        assert_eq!(report.rating(&RatingCriterion::CO2_SCRUBBER), None);
+        let report: Report = "01\n01\n".parse().unwrap();
+        assert_eq!(report.rating(&RatingCriterion::OXYGEN_GENERATOR), None);
+    }
+
+    #[test]
+    fn counts() {
+        let report: Report = INPUT_EXAMPLE.parse().unwrap();
+        assert_eq!(count_ones(report.values(), 5), [5, 7, 8, 5, 7]);
+
+        let values = (0..100_000)
+            .map(|i| {
+                let mut value = BitSet::new(130);
+                value.set(i % 130, true);
+                value.set(129, i % 3 == 0);
+                value
+            })
+            .collect::<Vec<_>>();
+        let expected = (0..130)
+            .map(|bit| values.iter().filter(|v| v.get(bit)).count())
+            .collect::<Vec<_>>();
+        assert_eq!(count_ones(&values, 130), expected);
+        assert_eq!(expected[129], 33_334);
+    }
+
+    #[test]
+    fn wide_report() {
+        // Every line of the example repeated 15 times
+        let report: Report = INPUT_EXAMPLE
+            .lines()
+            .map(|line| format!("{}\n", line.repeat(15)))
+            .collect::<String>()
+            .parse()
+            .unwrap();
+        assert_eq!(report.num_bits(), 75);
+        let (gamma_rate, epsilon_rate) = report.gamma_and_epsilon();
+        assert_eq!(gamma_rate.to_string(), "10110".repeat(15));
+        assert_eq!(epsilon_rate.to_string(), "01001".repeat(15));
+        assert_eq!(gamma_rate.to_usize(), None);
+        assert_eq!(report.oxygen().to_string(), "10111".repeat(15));
+        assert_eq!(report.co2().to_string(), "01010".repeat(15));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "00100\n1110\n".parse::<Report>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "line of 5 bits");
+
+        let err = "00100\n111100\n".parse::<Report>().unwrap_err();
+        assert_eq!(err.expected, "line of 5 bits");
+
+        let err = "00100\n11201\n".parse::<Report>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "binary digit");
+
+        assert!("".parse::<Report>().is_err());
+    }
+}
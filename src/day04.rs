@@ -0,0 +1,581 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Game;
+    type Answer1 = Score;
+    type Answer2 = Score;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Game::parse(input)
+    }
+
+    fn part1(game: &Self::Input) -> Self::Answer1 {
+        game.score(Winner::First)
+    }
+
+    fn part2(game: &Self::Input) -> Self::Answer2 {
+        game.score(Winner::Last)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winner {
+    First,
+    Last,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Score {
+    Won(u32),
+    /// Several boards won on the same draw.
+    Tie,
+    /// The numbers ran out first.
+    NoWinner,
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Score::Won(score) => write!(f, "{}", score),
+            Score::Tie => write!(f, "tie"),
+            Score::NoWinner => write!(f, "no winner"),
+        }
+    }
+}
+
+/// Number drawn when `winner` wins, times the sum of its unmarked numbers.
+pub fn score<const R: usize, const C: usize>(
+    numbers_drawn: &[u8],
+    bingo: &Bingo<R, C>,
+    winner: Winner,
+) -> Score {
+    let mut bingo = bingo.clone();
+    for n in numbers_drawn {
+        let winners = bingo.draw(*n);
+        if !winners.is_empty() && (winner == Winner::First || bingo.boards.is_empty()) {
+            let [winner_board] = winners.as_slice() else {
+                return Score::Tie;
+            };
+            let sum_unmarked: u32 = winner_board.unmarked().into_iter().map(u32::from).sum();
+            return Score::Won(*n as u32 * sum_unmarked);
+        }
+    }
+    Score::NoWinner
+}
+
+// Board sizes are const generics, so a game of any size read from the input
+// is one of a few instantiations, picked by rows then by columns
+macro_rules! games {
+    ($($size:literal => $rows:ident, $columns:ident);+ $(;)?) => {
+        /// Numbers drawn and boards of one of the supported sizes, from 2x2 to
+        /// 10x10.
+        #[derive(Debug, Clone)]
+        pub enum Game {
+            $($rows(Columns<$size>),)+
+        }
+
+        /// Numbers drawn and boards of `R` rows, by their number of columns.
+        #[derive(Debug, Clone)]
+        pub enum Columns<const R: usize> {
+            $($columns(Vec<u8>, Bingo<R, $size>),)+
+        }
+
+        impl Game {
+            /// Parses `input` with the size of its first board.
+            pub fn parse(input: &str) -> Result<Self, ParseError> {
+                let (rows, columns) = board_size(input)?;
+                match rows {
+                    $($size => Columns::parse(input, columns).map(Self::$rows),)+
+                    _ => Err(unsupported_size(input)),
+                }
+            }
+
+            pub fn score(&self, winner: Winner) -> Score {
+                match self {
+                    $(Self::$rows(game) => game.score(winner),)+
+                }
+            }
+        }
+
+        impl<const R: usize> Columns<R> {
+            fn parse(input: &str, columns: usize) -> Result<Self, ParseError> {
+                match columns {
+                    $($size => {
+                        let (numbers_drawn, bingo) = parse_input(input)?;
+                        Ok(Self::$columns(numbers_drawn, bingo))
+                    })+
+                    _ => Err(unsupported_size(input)),
+                }
+            }
+
+            pub fn score(&self, winner: Winner) -> Score {
+                match self {
+                    $(Self::$columns(numbers_drawn, bingo) => score(numbers_drawn, bingo, winner),)+
+                }
+            }
+        }
+    };
+}
+
+games! {
+    2 => Rows2, Columns2;
+    3 => Rows3, Columns3;
+    4 => Rows4, Columns4;
+    5 => Rows5, Columns5;
+    6 => Rows6, Columns6;
+    7 => Rows7, Columns7;
+    8 => Rows8, Columns8;
+    9 => Rows9, Columns9;
+    10 => Rows10, Columns10;
+}
+
+fn unsupported_size(input: &str) -> ParseError {
+    let parser = Parser::new(Day04::DAY, input);
+    let first_row = input.lines().nth(2).unwrap_or_default();
+    parser.error(first_row, "board from 2x2 to 10x10")
+}
+
+/// Size of the puzzle's boards.
+pub const BINGO_SIZE: usize = 5;
+
+/// Board of `R` rows and `C` columns.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Board<const R: usize, const C: usize = R> {
+    numbers: [[u8; C]; R],
+    is_marked: [[bool; C]; R],
+    marked: Vec<u8>,
+}
+
+impl<const R: usize, const C: usize> Board<R, C> {
+    pub fn new(numbers: [[u8; C]; R]) -> Self {
+        assert!(R > 0 && C > 0, "boards must not be empty");
+        Self {
+            numbers,
+            is_marked: [[false; C]; R],
+            marked: Vec::new(),
+        }
+    }
+
+    pub fn unmarked(&self) -> HashSet<u8> {
+        self.positions()
+            .filter(|(row, col)| !self.is_marked[*row][*col])
+            .map(|(row, col)| self.numbers[row][col])
+            .collect()
+    }
+
+    /// Marks `num` if it is on the board, returning whether it completed a
+    /// row or a column.
+    pub fn mark(&mut self, num: u8) -> bool {
+        self.mark_with(num, &WinRule::standard())
+    }
+
+    /// Marks `num` if it is on the board, returning whether the board then
+    /// wins under any of `rules`.
+    pub fn mark_with(&mut self, num: u8, rules: &[WinRule<R, C>]) -> bool {
+        let mut added = false;
+        for (row, col) in self.positions() {
+            if self.numbers[row][col] == num && !self.is_marked[row][col] {
+                self.is_marked[row][col] = true;
+                added = true;
+            }
+        }
+        if !added {
+            return false;
+        }
+        if !self.marked.contains(&num) {
+            self.marked.push(num);
+        }
+        rules.iter().any(|rule| rule.is_complete(&self.is_marked))
+    }
+
+    fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..R).flat_map(|row| (0..C).map(move |col| (row, col)))
+    }
+}
+
+impl<const R: usize, const C: usize> PartialEq<[[u8; C]; R]> for Board<R, C> {
+    fn eq(&self, other: &[[u8; C]; R]) -> bool {
+        &self.numbers == other
+    }
+}
+
+/// A way for a board to win, once every cell of some pattern is marked.
+#[derive(Debug, PartialEq, Clone)]
+pub enum WinRule<const R: usize, const C: usize = R> {
+    Rows,
+    Columns,
+    /// Either diagonal from a top corner, of `min(R, C)` cells
+    Diagonals,
+    FourCorners,
+    FullCard,
+    /// The cells set in the mask
+    Pattern([[bool; C]; R]),
+}
+
+impl<const R: usize, const C: usize> WinRule<R, C> {
+    /// Rules of the puzzle.
+    pub fn standard() -> Vec<Self> {
+        vec![Self::Rows, Self::Columns]
+    }
+
+    pub fn is_complete(&self, marked: &[[bool; C]; R]) -> bool {
+        let size = R.min(C);
+        match self {
+            Self::Rows => marked.iter().any(|row| row.iter().all(|m| *m)),
+            Self::Columns => (0..C).any(|col| marked.iter().all(|row| row[col])),
+            Self::Diagonals => {
+                (0..size).all(|i| marked[i][i]) || (0..size).all(|i| marked[i][C - 1 - i])
+            }
+            Self::FourCorners => {
+                marked[0][0] && marked[0][C - 1] && marked[R - 1][0] && marked[R - 1][C - 1]
+            }
+            Self::FullCard => marked.iter().flatten().all(|m| *m),
+            Self::Pattern(mask) => mask
+                .iter()
+                .flatten()
+                .zip(marked.iter().flatten())
+                .all(|(required, m)| !required || *m),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bingo<const R: usize = BINGO_SIZE, const C: usize = R> {
+    pub boards: Vec<Board<R, C>>,
+    /// A board wins when any of them is complete
+    pub rules: Vec<WinRule<R, C>>,
+}
+
+impl<const R: usize, const C: usize> Bingo<R, C> {
+    /// A game with the standard rules.
+    pub fn new(boards: Vec<Board<R, C>>) -> Self {
+        Self {
+            boards,
+            rules: WinRule::standard(),
+        }
+    }
+
+    pub fn with_rules(self, rules: Vec<WinRule<R, C>>) -> Self {
+        Self { rules, ..self }
+    }
+
+    pub fn draw(&mut self, num: u8) -> Vec<Board<R, C>> {
+        let rules = &self.rules;
+        self.boards
+            .extract_if(.., |b| b.mark_with(num, rules))
+            .collect()
+    }
+}
+
+/// Rows and columns of the first board of `input`, to pick the size to parse
+/// it with.
+pub fn board_size(input: &str) -> Result<(usize, usize), ParseError> {
+    let parser = Parser::new(Day04::DAY, input);
+    let mut board = input.lines().skip(2).take_while(|line| !line.is_empty());
+    let first_row = board
+        .next()
+        .ok_or_else(|| parser.error_after(input, "board row"))?;
+    Ok((board.count() + 1, first_row.split_whitespace().count()))
+}
+
+pub fn parse_input<const R: usize, const C: usize>(
+    input: &str,
+) -> Result<(Vec<u8>, Bingo<R, C>), ParseError> {
+    let parser = Parser::new(Day04::DAY, input);
+    let mut lines = input.lines();
+
+    // First line contains the numbers drawn, comma separated
+    let numbers_drawn: Vec<u8> = lines
+        .next()
+        .ok_or_else(|| parser.error(input, "numbers drawn"))?
+        .split(',')
+        .map(|e| parser.number(e))
+        .collect::<Result<_, _>>()?;
+
+    // Remaining lines will contain the bingo boards, each preceded by an
+    // empty line
+    let mut boards = Vec::new();
+    let mut numbers = [[0; C]; R];
+    let mut next_row = None;
+    for line in lines {
+        match next_row {
+            None if line.is_empty() => next_row = Some(0),
+            None => {
+                let expected = format!("empty line after {} board rows", R);
+                return Err(parser.error(line, expected));
+            }
+            Some(row) => {
+                numbers[row] = parse_row(&parser, line)?;
+                if row + 1 == R {
+                    boards.push(Board::new(numbers));
+                    next_row = None;
+                } else {
+                    next_row = Some(row + 1);
+                }
+            }
+        }
+    }
+
+    if next_row.is_some_and(|row| row > 0) {
+        return Err(parser.error_after(input, "board row"));
+    }
+
+    Ok((numbers_drawn, Bingo::new(boards)))
+}
+
+fn parse_row<const C: usize>(parser: &Parser, line: &str) -> Result<[u8; C], ParseError> {
+    let mut numbers = line.split_whitespace();
+    let mut row = [0; C];
+    for n in &mut row {
+        let number = numbers
+            .next()
+            .ok_or_else(|| parser.error_after(line, "number"))?;
+        *n = parser.number(number)?;
+    }
+    if let Some(extra) = numbers.next() {
+        return Err(parser.error(extra, format!("end of board row of {} numbers", C)));
+    }
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str =
+        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn example() {
+        let (numbers_drawn, mut bingo): (_, Bingo) = parse_input(INPUT_EXAMPLE).unwrap();
+
+        assert_eq!(
+            numbers_drawn,
+            [
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1
+            ]
+        );
+
+        assert_eq!(
+            bingo.boards,
+            [
+                [
+                    [22, 13, 17, 11, 0],
+                    [8, 2, 23, 4, 24],
+                    [21, 9, 14, 16, 7],
+                    [6, 10, 3, 18, 5],
+                    [1, 12, 20, 15, 19]
+                ],
+                [
+                    [3, 15, 0, 2, 22],
+                    [9, 18, 13, 17, 5],
+                    [19, 8, 7, 25, 23],
+                    [20, 11, 10, 24, 4],
+                    [14, 21, 16, 12, 6]
+                ],
+                [
+                    [14, 21, 17, 24, 4],
+                    [10, 16, 15, 9, 19],
+                    [18, 8, 23, 26, 20],
+                    [22, 11, 13, 6, 5],
+                    [2, 0, 12, 3, 7]
+                ]
+            ]
+        );
+
+        // Part 1
+        for n in [7, 4, 9, 5, 11] {
+            assert!(bingo.draw(n).is_empty());
+        }
+
+        for board in &bingo.boards {
+            assert_eq!(board.marked, [7, 4, 9, 5, 11]);
+        }
+
+        for n in [17, 23, 2, 0, 14, 21] {
+            assert!(bingo.draw(n).is_empty());
+        }
+        let winning_number = 24;
+        let winner_board = bingo.draw(winning_number).pop().unwrap();
+        assert_eq!(
+            winner_board,
+            [
+                [14, 21, 17, 24, 4],
+                [10, 16, 15, 9, 19],
+                [18, 8, 23, 26, 20],
+                [22, 11, 13, 6, 5],
+                [2, 0, 12, 3, 7]
+            ]
+        );
+
+        assert_eq!(*winner_board.marked.last().unwrap(), winning_number);
+        let sum_unmarked: u16 = winner_board.unmarked().into_iter().map(|n| n as u16).sum();
+        assert_eq!(sum_unmarked, 188);
+        assert_eq!(winning_number as u16 * sum_unmarked, 4512);
+
+        // Part 2
+        for n in [10, 16] {
+            bingo.draw(n);
+        }
+        assert_eq!(bingo.boards.len(), 1);
+        let last_number = 13;
+        let last_winner = bingo.draw(last_number).pop().unwrap();
+        assert_eq!(
+            last_winner,
+            [
+                [3, 15, 0, 2, 22],
+                [9, 18, 13, 17, 5],
+                [19, 8, 7, 25, 23],
+                [20, 11, 10, 24, 4],
+                [14, 21, 16, 12, 6]
+            ]
+        );
+        let sum_unmarked: u16 = last_winner.unmarked().into_iter().map(|n| n as u16).sum();
+        assert_eq!(sum_unmarked, 148);
+        assert_eq!(sum_unmarked * last_number as u16, 1924);
+    }
+
+    #[test]
+    fn rectangular_boards() {
+        let input = "3,6,9,1,5
+
+ 1  2  3  4
+ 5  6  7  8
+ 9 10 11 12
+";
+        assert_eq!(board_size(input).unwrap(), (3, 4));
+        let (numbers_drawn, mut bingo) = parse_input::<3, 4>(input).unwrap();
+        for n in &numbers_drawn[..4] {
+            assert!(bingo.draw(*n).is_empty());
+        }
+        // 3, 6 and 9 are not a row or a column, but 1, 5 and 9 are
+        let winners = bingo.draw(5);
+        assert_eq!(winners[0].marked, [3, 6, 9, 1, 5]);
+        assert_eq!(winners[0].unmarked().len(), 7);
+
+        let mut board = Board::new([[1, 2], [3, 4], [5, 6]]);
+        assert!(!board.mark(2));
+        assert!(board.mark(1));
+        assert_eq!(board.unmarked(), HashSet::from([3, 4, 5, 6]));
+
+        let err = parse_input::<3, 3>(input).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 11));
+        assert_eq!(err.expected, "end of board row of 3 numbers");
+        let err = parse_input::<2, 4>(input).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.expected, "empty line after 2 board rows");
+        let err = parse_input::<4, 4>(input).unwrap_err();
+        assert_eq!(err.expected, "board row");
+        assert!(board_size("1,2\n").is_err());
+    }
+
+    #[test]
+    fn win_rules() {
+        let (numbers_drawn, bingo): (_, Bingo) = parse_input(INPUT_EXAMPLE).unwrap();
+        // Number drawn when the first board wins, and its index
+        let first_winner = |rules| {
+            let mut game = bingo.clone().with_rules(rules);
+            numbers_drawn.iter().find_map(|n| {
+                let winner = game.draw(*n).pop()?;
+                let index = bingo
+                    .boards
+                    .iter()
+                    .position(|b| b.numbers == winner.numbers);
+                Some((*n, index?))
+            })
+        };
+        assert_eq!(first_winner(WinRule::standard()), Some((24, 2)));
+        assert_eq!(first_winner(vec![WinRule::Diagonals]), Some((2, 2)));
+        assert_eq!(first_winner(vec![WinRule::FourCorners]), Some((14, 2)));
+        assert_eq!(first_winner(vec![WinRule::FullCard]), Some((3, 1)));
+        assert_eq!(first_winner(vec![WinRule::Rows]), Some((24, 2)));
+        assert_eq!(first_winner(vec![WinRule::Columns]), Some((13, 1)));
+
+        // Center and its four neighbours
+        let mut plus = [[false; 5]; 5];
+        for (row, col) in [(1, 2), (2, 1), (2, 2), (2, 3), (3, 2)] {
+            plus[row][col] = true;
+        }
+        assert_eq!(
+            first_winner(vec![WinRule::Pattern(plus), WinRule::FullCard]),
+            Some((8, 1))
+        );
+
+        let mut board = Board::new([[1, 2, 3], [4, 5, 6]]);
+        let rules = [WinRule::Diagonals];
+        assert!(!board.mark_with(1, &rules));
+        assert!(board.mark_with(5, &rules));
+        assert!(WinRule::FourCorners.is_complete(&[[true, false, true], [true, false, true]]));
+    }
+
+    #[test]
+    fn inferred_size() {
+        let day = crate::get_day(4).unwrap();
+        let input = "1,2,3,4,5,6,7,8,9\n\n1 2 3\n4 5 6\n7 8 9\n\n9 8 7\n1 5 3\n6 4 2\n";
+        let game = day.parse(input).unwrap();
+        // 1, 2 and 3 complete the first board's top row, 5 the second's middle
+        // row
+        assert_eq!(
+            day.solve(game.as_ref(), 1),
+            (3 * (4 + 5 + 6 + 7 + 8 + 9)).to_string()
+        );
+        assert_eq!(
+            day.solve(game.as_ref(), 2),
+            (5 * (9 + 8 + 7 + 6)).to_string()
+        );
+
+        let game = Day04::parse(INPUT_EXAMPLE).unwrap();
+        assert!(matches!(game, Game::Rows5(Columns::Columns5(..))));
+        assert_eq!(Day04::part1(&game), Score::Won(4512));
+
+        // 1 and 4 complete the first column
+        let game = Day04::parse("1,4\n\n1 2 3\n4 5 6\n").unwrap();
+        assert!(matches!(game, Game::Rows2(Columns::Columns3(..))));
+        assert_eq!(Day04::part1(&game), Score::Won(4 * (2 + 3 + 5 + 6)));
+
+        let err = Day04::parse("1,2\n\n1 2 3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.expected, "board from 2x2 to 10x10");
+    }
+
+    #[test]
+    fn ties_and_no_winner() {
+        let game = Day04::parse("1,2,3\n\n1 2\n3 4\n\n1 2\n5 6\n").unwrap();
+        assert_eq!(Day04::part1(&game), Score::Tie);
+        assert_eq!(Day04::part2(&game), Score::Tie);
+
+        let game = Day04::parse("1,2\n\n1 2\n3 4\n\n5 6\n7 8\n").unwrap();
+        assert_eq!(Day04::part1(&game), Score::Won(2 * (3 + 4)));
+        assert_eq!(Day04::part2(&game), Score::NoWinner);
+        assert_eq!(Day04::part2(&game).to_string(), "no winner");
+    }
+}
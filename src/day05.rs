@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Answer1 {
+        let mut intersection_map = IntersectionMap::default();
+        horizontal_or_vertical_lines(lines)
+            .iter()
+            .for_each(|line| intersection_map.add_line(line));
+        intersection_map.points_with_overlap().len()
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Answer2 {
+        let mut intersection_map = IntersectionMap::default();
+        lines
+            .iter()
+            .for_each(|line| intersection_map.add_line(line));
+        intersection_map.points_with_overlap().len()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Line {
+    pub x1: u16,
+    pub y1: u16,
+    pub x2: u16,
+    pub y2: u16,
+}
+
+impl Line {
+    pub fn is_horizontal_or_vertical(&self) -> bool {
+        self.x1 == self.x2 || self.y1 == self.y2
+    }
+
+    pub fn points(&self) -> Vec<(u16, u16)> {
+        if self.x1 == self.x2 {
+            let x = self.x1;
+            ((u16::min(self.y1, self.y2))..=(u16::max(self.y1, self.y2)))
+                .map(|y| (x, y))
+                .collect()
+        } else if self.y1 == self.y2 {
+            let y = self.y1;
+            ((u16::min(self.x1, self.x2))..=(u16::max(self.x1, self.x2)))
+                .map(|x| (x, y))
+                .collect()
+        } else {
+            // Only ever horizontal, vertical, or diagonals with 45 degrees
+            assert!(self.x1.abs_diff(self.x2) == self.y1.abs_diff(self.y2));
+
+            let x_inc = if self.x1 < self.x2 { 1 } else { -1 };
+            let y_inc = if self.y1 < self.y2 { 1 } else { -1 };
+
+            (0..=(self.x1.abs_diff(self.x2) as i16))
+                .map(|i| {
+                    (
+                        (self.x1 as i16 + i * x_inc) as u16,
+                        (self.y1 as i16 + i * y_inc) as u16,
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+pub fn horizontal_or_vertical_lines(lines: &[Line]) -> Vec<&Line> {
+    lines
+        .iter()
+        .filter(|l| l.is_horizontal_or_vertical())
+        .collect()
+}
+
+impl std::str::FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new(Day05::DAY, s);
+        let (start, end) = parser.split_once(s, " -> ")?;
+        let (x1, y1) = parser.split_once(start, ",")?;
+        let (x2, y2) = parser.split_once(end, ",")?;
+        let line = Line {
+            x1: parser.number(x1)?,
+            y1: parser.number(y1)?,
+            x2: parser.number(x2)?,
+            y2: parser.number(y2)?,
+        };
+
+        // Only ever horizontal, vertical, or diagonals with 45 degrees
+        if !line.is_horizontal_or_vertical()
+            && line.x1.abs_diff(line.x2) != line.y1.abs_diff(line.y2)
+        {
+            return Err(parser.error(s, "horizontal, vertical or 45 degrees diagonal line"));
+        }
+        Ok(line)
+    }
+}
+
+#[derive(Default)]
+pub struct IntersectionMap {
+    map: HashMap<(u16, u16), u16>,
+}
+
+impl IntersectionMap {
+    pub fn add_point(&mut self, (x, y): (u16, u16)) {
+        if let Some(count) = self.map.get_mut(&(x, y)) {
+            *count += 1;
+        } else {
+            self.map.insert((x, y), 1);
+        }
+    }
+
+    pub fn add_line(&mut self, line: &Line) {
+        line.points()
+            .iter()
+            .for_each(|point| self.add_point(*point));
+    }
+
+    pub fn points_with_overlap(&self) -> Vec<(u16, u16)> {
+        self.map
+            .iter()
+            .filter_map(|(point, count)| if *count > 1 { Some(point) } else { None })
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    Parser::new(Day05::DAY, input).lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn example() {
+        // Input
+        let lines = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!(
+            lines,
+            [
+                Line {
+                    x1: 0,
+                    y1: 9,
+                    x2: 5,
+                    y2: 9
+                },
+                Line {
+                    x1: 8,
+                    y1: 0,
+                    x2: 0,
+                    y2: 8
+                },
+                Line {
+                    x1: 9,
+                    y1: 4,
+                    x2: 3,
+                    y2: 4
+                },
+                Line {
+                    x1: 2,
+                    y1: 2,
+                    x2: 2,
+                    y2: 1
+                },
+                Line {
+                    x1: 7,
+                    y1: 0,
+                    x2: 7,
+                    y2: 4
+                },
+                Line {
+                    x1: 6,
+                    y1: 4,
+                    x2: 2,
+                    y2: 0
+                },
+                Line {
+                    x1: 0,
+                    y1: 9,
+                    x2: 2,
+                    y2: 9
+                },
+                Line {
+                    x1: 3,
+                    y1: 4,
+                    x2: 1,
+                    y2: 4
+                },
+                Line {
+                    x1: 0,
+                    y1: 0,
+                    x2: 8,
+                    y2: 8
+                },
+                Line {
+                    x1: 5,
+                    y1: 5,
+                    x2: 8,
+                    y2: 2
+                },
+            ]
+        );
+
+        // Part 1
+        let horizonal_or_vertical_lines = horizontal_or_vertical_lines(&lines);
+        assert_eq!(
+            horizonal_or_vertical_lines,
+            [
+                &Line {
+                    x1: 0,
+                    y1: 9,
+                    x2: 5,
+                    y2: 9
+                },
+                &Line {
+                    x1: 9,
+                    y1: 4,
+                    x2: 3,
+                    y2: 4
+                },
+                &Line {
+                    x1: 2,
+                    y1: 2,
+                    x2: 2,
+                    y2: 1
+                },
+                &Line {
+                    x1: 7,
+                    y1: 0,
+                    x2: 7,
+                    y2: 4
+                },
+                &Line {
+                    x1: 0,
+                    y1: 9,
+                    x2: 2,
+                    y2: 9
+                },
+                &Line {
+                    x1: 3,
+                    y1: 4,
+                    x2: 1,
+                    y2: 4
+                },
+            ]
+        );
+
+        let example_line = Line {
+            x1: 9,
+            y1: 4,
+            x2: 3,
+            y2: 4,
+        };
+        assert_eq!(
+            example_line.points(),
+            [(3, 4), (4, 4), (5, 4), (6, 4), (7, 4), (8, 4), (9, 4)]
+        );
+
+        let mut intersection_map = IntersectionMap::default();
+        horizonal_or_vertical_lines
+            .iter()
+            .for_each(|line| intersection_map.add_line(line));
+
+        assert_eq!(intersection_map.points_with_overlap().len(), 5);
+
+        // Part 2
+        let diagonal_line = Line {
+            x1: 8,
+            y1: 0,
+            x2: 0,
+            y2: 8,
+        };
+        assert_eq!(
+            diagonal_line.points(),
+            [
+                (8, 0),
+                (7, 1),
+                (6, 2),
+                (5, 3),
+                (4, 4),
+                (3, 5),
+                (2, 6),
+                (1, 7),
+                (0, 8)
+            ]
+        );
+
+        let mut intersection_map = IntersectionMap::default();
+        lines
+            .iter()
+            .for_each(|line| intersection_map.add_line(line));
+        assert_eq!(intersection_map.points_with_overlap().len(), 12);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_input("0,9 -> 5,9\n8,0 -> 0,8\n3,4 -> 1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 9));
+        assert_eq!(err.expected, "','");
+
+        let err = parse_input("0,9 -> 5,9\n0,0 -> 1,2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
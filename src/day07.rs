@@ -0,0 +1,75 @@
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<u16>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(positions: &Self::Input) -> Self::Answer1 {
+        mininum_fuel(positions, cost1)
+    }
+
+    fn part2(positions: &Self::Input) -> Self::Answer2 {
+        mininum_fuel(positions, cost2)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<u16>, ParseError> {
+    let parser = Parser::new(Day07::DAY, input);
+    input.trim().split(',').map(|e| parser.number(e)).collect()
+}
+
+pub fn mininum_fuel<F>(positions: &[u16], cost_fn: F) -> u32
+where
+    F: Fn(u16, u16) -> u32,
+{
+    let min = *positions.iter().min().unwrap();
+    let max = *positions.iter().max().unwrap();
+
+    (min..=max)
+        .map(|pos| positions.iter().map(|p| cost_fn(*p, pos)).sum())
+        .min()
+        .unwrap()
+}
+
+#[inline]
+pub fn cost1(p1: u16, p2: u16) -> u32 {
+    p1.abs_diff(p2) as u32
+}
+
+#[inline]
+pub fn cost2(p1: u16, p2: u16) -> u32 {
+    let d = cost1(p1, p2);
+    ((1 + d) * d) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn example() {
+        let positions = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!(positions, [16, 1, 2, 0, 4, 2, 7, 1, 2, 14]);
+
+        // Part 1
+        assert_eq!(mininum_fuel(&positions, cost1), 37);
+
+        // Part 2
+        assert_eq!(mininum_fuel(&positions, cost2), 168);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    error::{ParseError, Parser},
+    grid::Grid,
+    solution::Solution,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(heights: &Self::Input) -> Self::Answer1 {
+        sum_risk_low_points(heights)
+    }
+
+    fn part2(heights: &Self::Input) -> Self::Answer2 {
+        product_three_largest_basins(heights)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    let parser = Parser::new(Day09::DAY, input);
+    Grid::parse(&parser, input, "digit", |c| c.to_digit(10).map(|d| d as u8))
+}
+
+pub fn sum_risk_low_points(heights: &Grid<u8>) -> usize {
+    heights
+        .positions()
+        .filter(|&pos| {
+            heights
+                .orthogonal_neighbours(pos)
+                .all(|neighbour| heights[pos] < heights[neighbour])
+        })
+        .map(|pos| heights[pos] as usize + 1)
+        .sum()
+}
+
+pub fn product_three_largest_basins(heights: &Grid<u8>) -> usize {
+    let mut sizes = heights
+        .regions(|height| *height != 9)
+        .iter()
+        .map(Vec::len)
+        .collect::<Vec<_>>();
+    sizes.sort_unstable();
+
+    sizes.into_iter().rev().take(3).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn example() {
+        let heights = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!((heights.width(), heights.height()), (10, 5));
+        assert_eq!(heights.to_string(), INPUT_EXAMPLE);
+
+        assert_eq!(sum_risk_low_points(&heights), 15);
+        assert_eq!(product_three_largest_basins(&heights), 1134);
+    }
+}
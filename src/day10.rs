@@ -0,0 +1,253 @@
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Vec<Bracket>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Answer1 {
+        total_syntax_error_score(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Answer2 {
+        middle_score_incomplete_lines(lines)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Bracket>>, ParseError> {
+    let parser = Parser::new(Day10::DAY, input);
+    if input.trim().is_empty() {
+        return Err(parser.error(input, "bracket"));
+    }
+    input
+        .lines()
+        .map(|line| match line.is_empty() {
+            true => Err(parser.error(line, "bracket")),
+            false => parser.chars(line, "bracket", |c| Bracket::try_from(c).ok()),
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Bracket {
+    pub op: BracketOp,
+    pub shape: BracketShape,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BracketOp {
+    Open,
+    Close,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BracketShape {
+    Round,
+    Square,
+    Curly,
+    Arrow,
+}
+
+impl TryFrom<char> for Bracket {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let bracket = match c {
+            '(' => Bracket {
+                op: BracketOp::Open,
+                shape: BracketShape::Round,
+            },
+            ')' => Bracket {
+                op: BracketOp::Close,
+                shape: BracketShape::Round,
+            },
+            '[' => Bracket {
+                op: BracketOp::Open,
+                shape: BracketShape::Square,
+            },
+            ']' => Bracket {
+                op: BracketOp::Close,
+                shape: BracketShape::Square,
+            },
+            '{' => Bracket {
+                op: BracketOp::Open,
+                shape: BracketShape::Curly,
+            },
+            '}' => Bracket {
+                op: BracketOp::Close,
+                shape: BracketShape::Curly,
+            },
+            '<' => Bracket {
+                op: BracketOp::Open,
+                shape: BracketShape::Arrow,
+            },
+            '>' => Bracket {
+                op: BracketOp::Close,
+                shape: BracketShape::Arrow,
+            },
+            _ => return Err(c),
+        };
+        Ok(bracket)
+    }
+}
+
+impl From<&Bracket> for char {
+    fn from(b: &Bracket) -> Self {
+        match b.op {
+            BracketOp::Open => b.shape.opening_char(),
+            BracketOp::Close => b.shape.closing_char(),
+        }
+    }
+}
+
+impl BracketShape {
+    #[inline]
+    fn opening_char(&self) -> char {
+        match self {
+            BracketShape::Round => '(',
+            BracketShape::Square => '[',
+            BracketShape::Curly => '{',
+            BracketShape::Arrow => '<',
+        }
+    }
+
+    #[inline]
+    fn closing_char(&self) -> char {
+        match self {
+            BracketShape::Round => ')',
+            BracketShape::Square => ']',
+            BracketShape::Curly => '}',
+            BracketShape::Arrow => '>',
+        }
+    }
+}
+
+impl PartialEq<char> for Bracket {
+    fn eq(&self, other: &char) -> bool {
+        &char::from(self) == other
+    }
+}
+
+enum LineType {
+    Corrupt(BracketShape),
+    Incomplete(Vec<BracketShape>),
+    /// Not in the puzzle's input, scored neither as corrupt nor incomplete
+    Complete,
+}
+
+fn analyze(brackets: &[Bracket]) -> LineType {
+    let mut stack = vec![];
+    for b in brackets {
+        match b.op {
+            BracketOp::Open => stack.push(b.shape),
+            BracketOp::Close => {
+                if stack.pop() != Some(b.shape) {
+                    return LineType::Corrupt(b.shape);
+                }
+            }
+        }
+    }
+    match stack.is_empty() {
+        true => LineType::Complete,
+        false => LineType::Incomplete(stack),
+    }
+}
+
+pub fn syntax_error_score(brackets: &[Bracket]) -> Option<usize> {
+    if let LineType::Corrupt(shape) = analyze(brackets) {
+        let score = match shape {
+            BracketShape::Round => 3,
+            BracketShape::Square => 57,
+            BracketShape::Curly => 1197,
+            BracketShape::Arrow => 25137,
+        };
+        Some(score)
+    } else {
+        None
+    }
+}
+
+pub fn total_syntax_error_score(lines: &[Vec<Bracket>]) -> usize {
+    lines
+        .iter()
+        .filter_map(|line| syntax_error_score(line))
+        .sum()
+}
+
+pub fn completion_score(brackets: &[Bracket]) -> Option<usize> {
+    if let LineType::Incomplete(pending) = analyze(brackets) {
+        let score = pending.iter().rev().fold(0, |acc, s| {
+            let points = match s {
+                BracketShape::Round => 1,
+                BracketShape::Square => 2,
+                BracketShape::Curly => 3,
+                BracketShape::Arrow => 4,
+            };
+            5 * acc + points
+        });
+        Some(score)
+    } else {
+        None
+    }
+}
+
+pub fn middle_score_incomplete_lines(lines: &[Vec<Bracket>]) -> usize {
+    let mut scores = lines
+        .iter()
+        .filter_map(|line| completion_score(line))
+        .collect::<Vec<_>>();
+
+    let len = scores.len();
+    // "There will always be an odd number of scores to consider."
+    assert!(len % 2 != 0);
+    scores.select_nth_unstable(len / 2);
+    scores[scores.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn example() {
+        let lines = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!(total_syntax_error_score(&lines), 26397);
+        assert_eq!(middle_score_incomplete_lines(&lines), 288957);
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let err = parse_input("[(\n\n<>\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "bracket");
+        assert!(parse_input("").is_err());
+
+        let lines = parse_input(")(\n()\n[\n").unwrap();
+        assert_eq!(total_syntax_error_score(&lines), 3);
+        assert_eq!(completion_score(&lines[1]), None);
+        assert_eq!(middle_score_incomplete_lines(&lines), 2);
+    }
+}
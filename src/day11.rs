@@ -0,0 +1,179 @@
+use crate::{
+    error::{ParseError, Parser},
+    grid::{Grid, Pos},
+    solution::Solution,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = EnergyLevels;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(energy_levels: &Self::Input) -> Self::Answer1 {
+        energy_levels.clone().step(100)
+    }
+
+    fn part2(energy_levels: &Self::Input) -> Self::Answer2 {
+        energy_levels.clone().steps_to_sync()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EnergyLevels {
+    levels: Grid<u8>,
+}
+
+impl std::str::FromStr for EnergyLevels {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new(Day11::DAY, s);
+        let levels = Grid::parse(&parser, s, "digit", |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(Self { levels })
+    }
+}
+
+impl EnergyLevels {
+    pub fn levels(&self) -> &Grid<u8> {
+        &self.levels
+    }
+
+    pub fn flash(&mut self, pos: Pos) -> usize {
+        let mut num_flashes = 1;
+        self.levels[pos] = 0;
+        let neighbours = self.levels.all_neighbours(pos).collect::<Vec<_>>();
+        for neighbour in neighbours {
+            if self.levels[neighbour] == 0 {
+                // already flashed
+                continue;
+            }
+            self.levels[neighbour] += 1;
+            if self.levels[neighbour] > 9 {
+                num_flashes += self.flash(neighbour);
+            }
+        }
+        num_flashes
+    }
+
+    pub fn step(&mut self, num_steps: u8) -> usize {
+        let mut num_flashes = 0;
+        for _ in 0..num_steps {
+            // First pass increases +1 in everyone
+            for level in self.levels.iter_mut() {
+                *level += 1;
+            }
+            // Second pass checks for the flashes
+            for pos in self.levels.positions() {
+                if self.levels[pos] > 9 {
+                    num_flashes += self.flash(pos);
+                }
+            }
+        }
+        num_flashes
+    }
+
+    pub fn steps_to_sync(&mut self) -> usize {
+        for i in std::iter::successors(Some(1), |n| Some(n + 1)) {
+            if self.step(1) == self.levels.len() {
+                return i;
+            }
+        }
+        usize::MAX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn example() {
+        let mut energy_levels: EnergyLevels = INPUT_EXAMPLE.parse().unwrap();
+
+        assert_eq!(energy_levels.levels.to_string(), INPUT_EXAMPLE);
+
+        let num_flashes = energy_levels.step(1);
+        assert_eq!(num_flashes, 0);
+        assert_eq!(
+            energy_levels,
+            "6594254334
+3856965822
+6375667284
+7252447257
+7468496589
+5278635756
+3287952832
+7993992245
+5957959665
+6394862637"
+                .parse()
+                .unwrap()
+        );
+
+        let num_flashes = energy_levels.step(1);
+        assert_eq!(
+            energy_levels,
+            "8807476555
+5089087054
+8597889608
+8485769600
+8700908800
+6600088989
+6800005943
+0000007456
+9000000876
+8700006848"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(num_flashes, 35);
+
+        let num_flashes = energy_levels.step(1);
+        assert_eq!(
+            energy_levels,
+            "0050900866
+8500800575
+9900000039
+9700000041
+9935080063
+7712300000
+7911250009
+2211130000
+0421125000
+0021119000"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(num_flashes, 45);
+
+        let mut energy_levels: EnergyLevels = INPUT_EXAMPLE.parse().unwrap();
+        assert_eq!(energy_levels.step(10), 204);
+
+        let mut energy_levels: EnergyLevels = INPUT_EXAMPLE.parse().unwrap();
+        assert_eq!(energy_levels.step(100), 1656);
+
+        // Part 2
+        let mut energy_levels: EnergyLevels = INPUT_EXAMPLE.parse().unwrap();
+        assert_eq!(energy_levels.steps_to_sync(), 195);
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+    trace,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = CaveMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cave_map: &Self::Input) -> Self::Answer1 {
+        cave_map.num_paths(VisitMode::Once)
+    }
+
+    fn part2(cave_map: &Self::Input) -> Self::Answer2 {
+        cave_map.num_paths(VisitMode::SingleTwice)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Cave {
+    Start,
+    End,
+    Big(String),
+    Small(String),
+}
+
+impl Cave {
+    fn new(s: &str) -> Option<Self> {
+        let cave = if s == "start" {
+            Cave::Start
+        } else if s == "end" {
+            Cave::End
+        } else if s.is_empty() {
+            return None;
+        } else if s.chars().all(char::is_uppercase) {
+            Cave::Big(s.to_string())
+        } else if s.chars().all(char::is_lowercase) {
+            Cave::Small(s.to_string())
+        } else {
+            return None;
+        };
+        Some(cave)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<CaveMap, ParseError> {
+    let parser = Parser::new(Day12::DAY, input);
+    let cave = |s| Cave::new(s).ok_or_else(|| parser.error(s, "cave name"));
+    let mut connections = HashMap::<Cave, HashSet<Cave>>::new();
+
+    for line in input.lines() {
+        let (cave1, cave2) = parser.split_once(line, "-")?;
+        let (cave1, cave2) = (cave(cave1)?, cave(cave2)?);
+
+        if let Some(connections_cave1) = connections.get_mut(&cave1) {
+            connections_cave1.insert(cave2.clone());
+        } else {
+            connections.insert(cave1.clone(), HashSet::from([cave2.clone()]));
+        }
+
+        if let Some(connections_cave2) = connections.get_mut(&cave2) {
+            connections_cave2.insert(cave1.clone());
+        } else {
+            connections.insert(cave2, HashSet::from([cave1]));
+        }
+    }
+
+    for (cave, name) in [(Cave::Start, "start"), (Cave::End, "end")] {
+        if !connections.contains_key(&cave) {
+            let expected = format!("connection to '{}'", name);
+            return Err(parser.error_after(input.trim_end(), expected));
+        }
+    }
+
+    Ok(CaveMap { connections })
+}
+
+#[derive(Debug)]
+pub struct CaveMap {
+    connections: HashMap<Cave, HashSet<Cave>>,
+}
+
+impl CaveMap {
+    pub fn num_paths(&self, mode: VisitMode) -> usize {
+        let mut count = 0;
+        let mut paths = VecDeque::from([Path::new(mode.clone())]);
+
+        while !paths.is_empty() {
+            let path = paths.pop_front().unwrap();
+            let last_cave = path.last_cave();
+            trace!(
+                Trace,
+                "path at {:?} after {} visits, {} queued",
+                last_cave,
+                path.visited.values().sum::<usize>(),
+                paths.len()
+            );
+
+            let cave_connections = self.connections.get(last_cave).unwrap();
+            if cave_connections.iter().any(|c| matches!(c, Cave::End)) {
+                count += 1;
+            }
+
+            let pushable = cave_connections
+                .iter()
+                .filter(|c| path.can_visit(c))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for (path, next) in itertools::repeat_n(path, pushable.len()).zip(pushable) {
+                paths.push_back(Path::from(path, next));
+            }
+        }
+
+        trace!(Debug, "{} paths visiting {:?}", count, mode);
+        count
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Path {
+    last: Cave,
+    visited: HashMap<Cave, usize>,
+    single_cave_was_visited_twice: bool,
+    mode: VisitMode,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum VisitMode {
+    Once,
+    SingleTwice,
+}
+
+impl Path {
+    fn new(mode: VisitMode) -> Self {
+        let start = Cave::Start;
+        Self {
+            last: start.clone(),
+            visited: HashMap::from([(start, 1)]),
+            single_cave_was_visited_twice: false,
+            mode,
+        }
+    }
+
+    #[inline]
+    fn from(mut path: Path, next: Cave) -> Self {
+        path.add_cave(next);
+        path
+    }
+
+    fn add_cave(&mut self, cave: Cave) {
+        self.last = cave.clone();
+
+        match self.mode {
+            VisitMode::Once => {
+                assert!(matches!(cave, Cave::Big(_)) || !self.visited.contains_key(&cave));
+                self.visited.insert(cave, 1);
+            }
+            VisitMode::SingleTwice => {
+                if let Some(num_visits) = self.visited.get_mut(&cave) {
+                    assert!(matches!(cave, Cave::Big(_)) || num_visits < &mut 2);
+                    *num_visits += 1;
+                    if matches!(cave, Cave::Small(_)) {
+                        self.single_cave_was_visited_twice = true;
+                    }
+                } else {
+                    self.visited.insert(cave, 1);
+                }
+            }
+        }
+    }
+
+    fn can_visit(&self, cave: &Cave) -> bool {
+        match cave {
+            Cave::Start | Cave::End => false,
+            Cave::Big(_) => true,
+            Cave::Small(_) => match self.mode {
+                VisitMode::Once => !self.visited.contains_key(cave),
+                VisitMode::SingleTwice => {
+                    if self.single_cave_was_visited_twice {
+                        !self.visited.contains_key(cave)
+                    } else {
+                        self.visited.get(cave).unwrap_or(&0) < &2
+                    }
+                }
+            },
+        }
+    }
+
+    fn last_cave(&self) -> &Cave {
+        &self.last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE1: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    const INPUT_EXAMPLE2: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const INPUT_EXAMPLE3: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn example() {
+        // Part 1
+        let cave_map: CaveMap = parse_input(INPUT_EXAMPLE1).unwrap();
+        assert_eq!(cave_map.num_paths(VisitMode::Once), 10);
+
+        let cave_map: CaveMap = parse_input(INPUT_EXAMPLE2).unwrap();
+        assert_eq!(cave_map.num_paths(VisitMode::Once), 19);
+
+        let cave_map: CaveMap = parse_input(INPUT_EXAMPLE3).unwrap();
+        assert_eq!(cave_map.num_paths(VisitMode::Once), 226);
+
+        // Part 2
+        let cave_map: CaveMap = parse_input(INPUT_EXAMPLE1).unwrap();
+        assert_eq!(cave_map.num_paths(VisitMode::SingleTwice), 36);
+
+        let cave_map: CaveMap = parse_input(INPUT_EXAMPLE2).unwrap();
+        assert_eq!(cave_map.num_paths(VisitMode::SingleTwice), 103);
+
+        let cave_map: CaveMap = parse_input(INPUT_EXAMPLE3).unwrap();
+        assert_eq!(cave_map.num_paths(VisitMode::SingleTwice), 3509);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_input("start-A\nA-b\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.expected, "connection to 'end'");
+
+        let err = parse_input("").unwrap_err();
+        assert_eq!(err.expected, "connection to 'start'");
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (Dots, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((dots, folds): &Self::Input) -> Self::Answer1 {
+        let mut dots = dots.clone();
+        fold(&mut dots, &folds[0]);
+        dots.len()
+    }
+
+    fn part2((dots, folds): &Self::Input) -> Self::Answer2 {
+        let mut dots = dots.clone();
+        for f in folds {
+            fold(&mut dots, f);
+        }
+        plot(&dots)
+    }
+}
+
+#[derive(Debug)]
+
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+pub type Dots = HashSet<(usize, usize)>;
+
+pub fn parse_input(input: &str) -> Result<(Dots, Vec<Fold>), ParseError> {
+    let parser = Parser::new(Day13::DAY, input);
+    let (dot_lines, fold_lines) = parser.split_once(input, "\n\n")?;
+
+    let dots = dot_lines
+        .lines()
+        .map(|line| {
+            let (x, y) = parser.split_once(line, ",")?;
+            Ok((parser.number(x)?, parser.number(y)?))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let folds = fold_lines
+        .lines()
+        .map(|line| {
+            let fold = parser.strip_prefix(line, "fold along ")?;
+            let (axis, value) = parser.split_once(fold, "=")?;
+            let value = parser.number(value)?;
+            match axis {
+                "x" => Ok(Fold::X(value)),
+                "y" => Ok(Fold::Y(value)),
+                _ => Err(parser.error(axis, "'x' or 'y'")),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if folds.is_empty() {
+        return Err(parser.error_after(input, "fold instruction"));
+    }
+
+    Ok((dots, folds))
+}
+
+pub fn fold(dots: &mut HashSet<(usize, usize)>, fold: &Fold) {
+    match fold {
+        Fold::X(xf) => {
+            let foldable: HashSet<_> = dots.extract_if(|(x, _)| x > xf).collect();
+            for (x, y) in foldable {
+                if let Some(x) = (2 * xf).checked_sub(x) {
+                    dots.insert((x, y));
+                }
+            }
+        }
+        Fold::Y(yf) => {
+            let foldable: HashSet<_> = dots.extract_if(|(_, y)| y > yf).collect();
+            for (x, y) in foldable {
+                if let Some(y) = (2 * yf).checked_sub(y) {
+                    dots.insert((x, y));
+                }
+            }
+        }
+    }
+}
+
+pub fn plot(dots: &HashSet<(usize, usize)>) -> String {
+    let max_x = dots.iter().max_by_key(|(x, _)| x).unwrap().0;
+    let max_y = dots.iter().max_by_key(|(_, y)| y).unwrap().1;
+
+    let mut output = String::new();
+    for y in 0..(max_y + 1) {
+        for x in 0..(max_x + 1) {
+            if dots.contains(&(x, y)) {
+                output.push('█');
+            } else {
+                output.push(' ');
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn example() {
+        let (mut dots, folds) = parse_input(INPUT_EXAMPLE).unwrap();
+
+        fold(&mut dots, &folds[0]);
+        assert_eq!(dots.len(), 17);
+
+        // Part 2
+        fold(&mut dots, &folds[1]);
+        assert_eq!(
+            plot(&dots),
+            "█████
+█   █
+█   █
+█   █
+█████
+"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    error::{ParseError, Parser},
+    grid::Grid,
+    solution::Solution,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = (Vec<bool>, Image);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((algorithm, input_image): &Self::Input) -> Self::Answer1 {
+        let enhanced = enhance(input_image.clone(), algorithm);
+        let enhanced2 = enhance(enhanced, algorithm);
+        enhanced2.num_pixels_lit()
+    }
+
+    fn part2((algorithm, input_image): &Self::Input) -> Self::Answer2 {
+        let enhanced50 = enhance_multiple(input_image.clone(), algorithm, 50);
+        enhanced50.num_pixels_lit()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    pixels: Grid<bool>,
+    boundary: usize,
+}
+
+impl std::fmt::Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixels = self.pixels.map(|pixel| if *pixel { '#' } else { '.' });
+        write!(f, "{}", pixels)
+    }
+}
+
+impl Image {
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    pub fn num_pixels_lit(&self) -> usize {
+        self.pixels.iter().filter(|pixel| **pixel).count()
+    }
+}
+
+pub fn parse_input(s: &str) -> Result<(Vec<bool>, Image), ParseError> {
+    let parser = Parser::new(Day20::DAY, s);
+    let (algorithm_line, input_image) = parser.split_once(s.trim(), "\n\n")?;
+
+    let char_to_bool = |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    };
+
+    let algorithm = parser.chars(algorithm_line, "'.' or '#'", char_to_bool)?;
+    if algorithm.len() != 512 {
+        return Err(parser.error(algorithm_line, "512 pixels algorithm"));
+    }
+    let input_image = Grid::parse(&parser, input_image, "'.' or '#'", char_to_bool)?;
+    Ok((
+        algorithm,
+        Image {
+            pixels: input_image,
+            boundary: 0,
+        },
+    ))
+}
+
+pub fn enhance(input: Image, algorithm: &[bool]) -> Image {
+    // The output grows by one pixel on every side, so output pixel (i, j)
+    // is centered on input pixel (i - 1, j - 1)
+    let output_image = Grid::from_fn(input.pixels.width() + 2, input.pixels.height() + 2, |pos| {
+        let binary_sequence = (-2..=0)
+            .flat_map(|di| (-2..=0).map(move |dj| (di, dj)))
+            .fold(0, |acc, delta| {
+                let bit_val = match input.pixels.offset(pos, delta) {
+                    Some(pos) => usize::from(input.pixels[pos]),
+                    None => input.boundary,
+                };
+                (acc << 1) | bit_val
+            });
+        algorithm[binary_sequence]
+    });
+
+    let boundary = (0..9).fold(0, |acc, _| (acc << 1) | input.boundary);
+    let boundary = if algorithm[boundary] { 1 } else { 0 };
+
+    Image {
+        pixels: output_image,
+        boundary,
+    }
+}
+
+pub fn enhance_multiple(input: Image, algorithm: &[bool], n: usize) -> Image {
+    (0..n).fold(input, |acc, _| enhance(acc, algorithm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###
+";
+
+    #[test]
+    fn example() {
+        let (algorithm, input_image) = parse_input(INPUT_EXAMPLE).unwrap();
+        let enhanced = enhance(input_image.clone(), &algorithm);
+        assert_eq!(
+            enhanced.to_string(),
+            ".##.##.
+#..#.#.
+##.#..#
+####..#
+.#..##.
+..##..#
+...#.#.
+"
+        );
+        let enhanced = enhance(enhanced, &algorithm);
+        assert_eq!(
+            enhanced.to_string(),
+            ".......#.
+.#..#.#..
+#.#...###
+#...##.#.
+#.....#.#
+.#.#####.
+..#.#####
+...##.##.
+....###..
+"
+        );
+        assert_eq!(enhanced.num_pixels_lit(), 35);
+
+        let enhanced2 = enhance_multiple(input_image.clone(), &algorithm, 2);
+        assert_eq!(
+            enhanced2.to_string(),
+            ".......#.
+.#..#.#..
+#.#...###
+#...##.#.
+#.....#.#
+.#.#####.
+..#.#####
+...##.##.
+....###..
+"
+        );
+        assert_eq!(enhanced2.num_pixels_lit(), 35);
+
+        let enhanced50 = enhance_multiple(input_image, &algorithm, 50);
+        assert_eq!(enhanced50.num_pixels_lit(), 3351);
+    }
+}
@@ -0,0 +1,331 @@
+use std::iter::Peekable;
+
+use crate::{
+    error::{ParseError, Parser},
+    solution::Solution,
+    trace,
+};
+
+// This is not actually solving, as the solving was done via inference
+// through the instructions in a spreadsheet ¯\_(ツ)_/¯
+pub const MAX_MODEL_NUMBER: [u8; 14] = [5, 3, 9, 9, 9, 9, 9, 5, 8, 2, 9, 3, 9, 9];
+pub const MIN_MODEL_NUMBER: [u8; 14] = [1, 1, 7, 2, 1, 1, 5, 1, 1, 1, 8, 1, 7, 5];
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Self::Answer1 {
+        check_model_number(Monad::new(instructions.clone()), &MAX_MODEL_NUMBER)
+    }
+
+    fn part2(instructions: &Self::Input) -> Self::Answer2 {
+        check_model_number(Monad::new(instructions.clone()), &MIN_MODEL_NUMBER)
+    }
+}
+
+fn check_model_number(mut monad: Monad, digits: &[u8]) -> u64 {
+    for d in digits {
+        match monad.insert_digit(*d) {
+            Err(MonadErr::NeedsInput) => continue,
+            Ok(ModelNumberStatus::Valid) => {
+                return digits.iter().fold(0, |acc, d| 10 * acc + *d as u64);
+            }
+            Ok(ModelNumberStatus::Invalid) | Err(MonadErr::Crash) => break,
+        }
+    }
+    panic!("model number was inferred for a different MONAD program")
+}
+
+#[derive(Debug, Clone)]
+pub enum Variable {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+use Variable::*;
+
+impl std::str::FromStr for Variable {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w" => Ok(W),
+            "x" => Ok(X),
+            "y" => Ok(Y),
+            "z" => Ok(Z),
+            _ => Err(Parser::new(Day24::DAY, s).error(s, "'w', 'x', 'y' or 'z'")),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Alu {
+    pub w: isize,
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Alu {
+    pub fn get(&self, var: &Variable) -> isize {
+        match var {
+            W => self.w,
+            X => self.x,
+            Y => self.y,
+            Z => self.z,
+        }
+    }
+
+    pub fn set(&mut self, var: &Variable, value: isize) {
+        match var {
+            W => self.w = value,
+            X => self.x = value,
+            Y => self.y = value,
+            Z => self.z = value,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Argument {
+    Num(isize),
+    Var(Variable),
+}
+
+impl std::str::FromStr for Argument {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(var) = s.parse::<Variable>() {
+            Ok(Argument::Var(var))
+        } else {
+            s.parse()
+                .map(Argument::Num)
+                .map_err(|_| Parser::new(Day24::DAY, s).error(s, "variable or number"))
+        }
+    }
+}
+
+impl Argument {
+    pub fn resolve(&self, alu: &Alu) -> isize {
+        match self {
+            Argument::Num(num) => *num,
+            Argument::Var(W) => alu.w,
+            Argument::Var(X) => alu.x,
+            Argument::Var(Y) => alu.y,
+            Argument::Var(Z) => alu.z,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Inp(Variable),
+    Add(Variable, Argument),
+    Mul(Variable, Argument),
+    Div(Variable, Argument),
+    Mod(Variable, Argument),
+    Eql(Variable, Argument),
+}
+
+use Instruction::*;
+
+impl std::str::FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = Parser::new(Day24::DAY, s);
+        let mut parts = s.split(' ');
+        let op = parts.next().unwrap_or_default();
+        let binary: Option<fn(Variable, Argument) -> Self> = match op {
+            "inp" => None,
+            "add" => Some(Add),
+            "mul" => Some(Mul),
+            "div" => Some(Div),
+            "mod" => Some(Mod),
+            "eql" => Some(Eql),
+            _ => return Err(parser.error(op, "'inp', 'add', 'mul', 'div', 'mod' or 'eql'")),
+        };
+        let mut operand =
+            |expected: &str| parts.next().ok_or_else(|| parser.error_after(s, expected));
+        let variable = parser.parse(operand("variable")?)?;
+        let instruction = match binary {
+            Some(binary) => binary(variable, parser.parse(operand("argument")?)?),
+            None => Inp(variable),
+        };
+        if let Some(extra) = parts.next() {
+            return Err(parser.error(extra, "end of line"));
+        }
+        Ok(instruction)
+    }
+}
+
+impl Instruction {
+    pub fn run(&self, alu: &mut Alu) -> Result<(), MonadErr> {
+        match self {
+            Inp(_) => unreachable!(),
+            Add(var, val) => alu.set(var, alu.get(var) + val.resolve(alu)),
+            Mul(var, val) => alu.set(var, alu.get(var) * val.resolve(alu)),
+            Div(var, val) => {
+                let b = val.resolve(alu);
+                if b == 0 {
+                    return Err(MonadErr::Crash);
+                }
+                alu.set(var, alu.get(var) / b);
+            }
+            Mod(var, val) => {
+                let a = alu.get(var);
+                let b = val.resolve(alu);
+                if a < 0 || b <= 0 {
+                    return Err(MonadErr::Crash);
+                }
+                alu.set(var, a % b);
+            }
+            Eql(var, val) => {
+                let a = alu.get(var);
+                let b = val.resolve(alu);
+                alu.set(var, if a == b { 1 } else { 0 });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monad {
+    alu: Alu,
+    instructions: Peekable<std::vec::IntoIter<Instruction>>,
+}
+
+impl Monad {
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        Self {
+            alu: Alu::default(),
+            instructions: instructions.into_iter().peekable(),
+        }
+    }
+}
+
+pub enum ModelNumberStatus {
+    Valid,
+    Invalid,
+}
+
+pub enum MonadErr {
+    NeedsInput,
+    Crash,
+}
+
+impl Monad {
+    pub fn model_number_status(&mut self) -> ModelNumberStatus {
+        assert!(self.instructions.peek().is_none());
+        if self.alu.z == 0 {
+            ModelNumberStatus::Valid
+        } else {
+            ModelNumberStatus::Invalid
+        }
+    }
+
+    pub fn insert_digit(&mut self, input: u8) -> Result<ModelNumberStatus, MonadErr> {
+        let instruction = self.instructions.next();
+        match instruction {
+            Some(Instruction::Inp(var)) => {
+                trace!(Debug, "input {}, z = {}", input, self.alu.z);
+                self.alu.set(&var, input as isize)
+            }
+            _ => unreachable!("run() should be called only when the next instruction is inp"),
+        }
+
+        while !matches!(self.instructions.peek(), Some(Instruction::Inp(_))) {
+            if let Some(instruction) = self.instructions.next() {
+                instruction.run(&mut self.alu)?;
+                trace!(Trace, "{:?} -> {:?}", instruction, self.alu);
+            } else {
+                trace!(Debug, "ran until the end, z = {}", self.alu.z);
+                return Ok(self.model_number_status());
+            }
+        }
+
+        Err(MonadErr::NeedsInput)
+    }
+}
+
+pub fn parse_input(s: &str) -> Result<Vec<Instruction>, ParseError> {
+    let parser = Parser::new(Day24::DAY, s);
+    let instructions: Vec<Instruction> = parser.lines(s)?;
+    // The MONAD reads a digit before running anything else.
+    match instructions.first() {
+        Some(Instruction::Inp(_)) => Ok(instructions),
+        _ => Err(parser.error(s.trim_start(), "'inp'")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE1: &str = "inp x
+mul x -1
+";
+    const INPUT_EXAMPLE2: &str = "inp z
+inp x
+mul z 3
+eql z x
+";
+
+    const INPUT_EXAMPLE3: &str = "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2
+";
+
+    #[test]
+    fn example() {
+        let instructions = parse_input(INPUT_EXAMPLE1).unwrap();
+        dbg!(instructions);
+
+        let instructions = parse_input(INPUT_EXAMPLE2).unwrap();
+        dbg!(instructions);
+
+        let instructions = parse_input(INPUT_EXAMPLE3).unwrap();
+        dbg!(instructions);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_input("inp w\nadd z\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.expected, "argument");
+
+        let err = parse_input("inp w\nmul x q\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.expected, "variable or number");
+
+        let err = parse_input("inp w\nsub x 1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse_input("add z 1\ninp w\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "'inp'");
+        assert!(parse_input("").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{ParseError, Parser},
+    grid::Grid,
+    solution::{NoAnswer, Solution},
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: usize = 1;
+
+    type Input = SeaCucumberMap;
+    type Answer1 = usize;
+    type Answer2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(sea_cucumber_map: &Self::Input) -> Self::Answer1 {
+        sea_cucumber_map.clone().step_until_stop()
+    }
+
+    fn part2(_: &Self::Input) -> Self::Answer2 {
+        NoAnswer
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cucumber {
+    East,
+    South,
+}
+
+#[derive(Debug, Clone)]
+pub enum Occupancy {
+    Busy(Cucumber),
+    Empty,
+}
+
+impl TryFrom<char> for Occupancy {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'v' => Ok(Self::Busy(Cucumber::South)),
+            '>' => Ok(Self::Busy(Cucumber::East)),
+            '.' => Ok(Self::Empty),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Occupancy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Occupancy::Busy(Cucumber::East) => ">",
+                Occupancy::Busy(Cucumber::South) => "v",
+                Occupancy::Empty => ".",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaCucumberMap {
+    positions: Grid<Occupancy>,
+}
+
+impl Display for SeaCucumberMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.positions)
+    }
+}
+
+impl SeaCucumberMap {
+    pub fn positions(&self) -> &Grid<Occupancy> {
+        &self.positions
+    }
+
+    pub fn step_until_stop(&mut self) -> usize {
+        for i in 1.. {
+            if !self.step() {
+                return i;
+            }
+        }
+        unreachable!()
+    }
+
+    pub fn step(&mut self) -> bool {
+        let east_moved = self.move_herd(Cucumber::East, (0, 1));
+        let south_moved = self.move_herd(Cucumber::South, (1, 0));
+        east_moved || south_moved
+    }
+
+    /// Moves every cucumber of a herd that can, all at once.
+    fn move_herd(&mut self, herd: Cucumber, delta: (isize, isize)) -> bool {
+        let moves = self
+            .positions
+            .positions()
+            .filter(|&pos| matches!(&self.positions[pos], Occupancy::Busy(c) if *c == herd))
+            .map(|pos| (pos, self.positions.wrapping_offset(pos, delta)))
+            .filter(|&(_, next)| matches!(self.positions[next], Occupancy::Empty))
+            .collect::<Vec<_>>();
+
+        for &(pos, next) in &moves {
+            self.positions[next] = Occupancy::Busy(herd.clone());
+            self.positions[pos] = Occupancy::Empty;
+        }
+        !moves.is_empty()
+    }
+}
+
+pub fn parse_input(s: &str) -> Result<SeaCucumberMap, ParseError> {
+    let parser = Parser::new(Day25::DAY, s);
+    let positions = Grid::parse(&parser, s, "'v', '>' or '.'", |c| c.try_into().ok())?;
+    Ok(SeaCucumberMap { positions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>
+";
+
+    #[test]
+    fn example() {
+        let mut sea_cucumber_map = parse_input(INPUT_EXAMPLE).unwrap();
+        assert_eq!(sea_cucumber_map.step_until_stop(), 58);
+        assert_eq!(
+            sea_cucumber_map.to_string(),
+            "..>>v>vv..
+..v.>>vv..
+..>>v>>vv.
+..>>>>>vv.
+v......>vv
+v>v....>>v
+vvv.....>>
+>vv......>
+.>v.vv.v..
+"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_input("v..\n.x>\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "'v', '>' or '.'");
+
+        let err = parse_input("v..\n.>\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
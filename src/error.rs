@@ -0,0 +1,205 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Error returned when an input doesn't match what a day expects, pointing
+/// at the offending token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line of the offending token
+    pub line: usize,
+    /// 1-based column (in chars) of the offending token
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token`, which must be a slice of `source`.
+    pub fn new(day: u8, source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(source, token);
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves an error found while parsing `inner`, a slice of `outer`, so it is
+    /// relative to `outer` instead.
+    pub fn rebase(mut self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Renders the error with the offending line of `input` and a caret under
+    /// the offending column.
+    pub fn render(&self, input: &str, source_name: &str) -> String {
+        let line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let number = self.line.to_string();
+        let padding = " ".repeat(number.len());
+        format!(
+            "error: day {}: expected {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}^\n",
+            self.day,
+            self.expected,
+            padding,
+            source_name,
+            self.line,
+            self.column,
+            padding,
+            number,
+            line,
+            padding,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based line and column of `token` within `source`. Tokens that are not
+/// a slice of `source` are placed at its end.
+fn position(source: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|offset| *offset <= source.len())
+        .unwrap_or(source.len());
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Helper for the days' parsers, creating errors relative to `source`.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    day: u8,
+    source: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u8, source: &'a str) -> Self {
+        Self { day, source }
+    }
+
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.day, self.source, token, expected)
+    }
+
+    /// Error for something missing right after `s`.
+    pub fn error_after(&self, s: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&s[s.len()..], expected)
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, "number"))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error_after(s, format!("'{}'", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("'{}'", prefix)))
+    }
+
+    /// Maps every char of `line` with `f`, failing on the first one it rejects.
+    pub fn chars<T>(
+        &self,
+        line: &str,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        line.char_indices()
+            .map(|(i, c)| f(c).ok_or_else(|| self.error(&line[i..], expected)))
+            .collect()
+    }
+
+    /// Parses `s` with its `FromStr` implementation, moving its errors so they
+    /// are relative to this parser's source.
+    pub fn parse<T>(&self, s: &str) -> Result<T, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        s.parse()
+            .map_err(|err: ParseError| err.rebase(self.source, s))
+    }
+
+    /// Parses every line of `s` with `parse`.
+    pub fn lines<T>(&self, s: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        s.lines().map(|line| self.parse(line)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "forward 5
+down 5
+forward x
+";
+
+    #[test]
+    fn positions() {
+        let parser = Parser::new(2, INPUT);
+        let line = INPUT.lines().nth(2).unwrap();
+        let (_, value) = parser.split_once(line, " ").unwrap();
+
+        let err = parser.number::<isize>(value).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 9));
+
+        let err = Parser::new(2, line).number::<isize>(value).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(
+            err.rebase(INPUT, line),
+            parser.number::<isize>(value).unwrap_err()
+        );
+
+        let err = parser.split_once("down5", " ").unwrap_err();
+        assert_eq!(err.expected, "' '");
+    }
+
+    #[test]
+    fn render() {
+        let err = ParseError {
+            day: 2,
+            line: 3,
+            column: 9,
+            expected: "number".to_string(),
+        };
+        assert_eq!(err.to_string(), "day 2, line 3, column 9: expected number");
+        assert_eq!(
+            err.render(INPUT, "inputs/day02.txt"),
+            "error: day 2: expected number
+ --> inputs/day02.txt:3:9
+  |
+3 | forward x
+  |         ^
+"
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use crate::{answers::parse_value, input};
+
+pub const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/cadubentzen/advent-of-code-2021-rs";
+
+/// Settings to download puzzle inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: String,
+    pub base_url: String,
+}
+
+impl Config {
+    /// Parses a config file, in the same TOML subset as the answers file.
+    ///
+    /// ```toml
+    /// session = "53616c7465645f5f..."
+    /// base_url = "http://localhost:8080" # optional
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut session = None;
+        let mut base_url = DEFAULT_BASE_URL.to_string();
+        for (i, line) in s.lines().enumerate() {
+            let error = |message: &str| format!("line {}: {}", i + 1, message);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected 'key = value'"))?;
+            let value = parse_value(value.trim()).ok_or_else(|| error("invalid value"))?;
+            match key.trim() {
+                "session" => session = Some(value),
+                "base_url" => base_url = value.trim_end_matches('/').to_string(),
+                key => return Err(error(&format!("unknown key '{}'", key))),
+            }
+        }
+        Ok(Self {
+            session: session.ok_or("missing session")?,
+            base_url,
+        })
+    }
+
+    pub fn read(path: &str) -> Result<Self, String> {
+        let s =
+            fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path, err))?;
+        Self::parse(&s).map_err(|err| format!("{}: {}", path, err))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Performs the HTTP requests, so that tests can replace it.
+pub trait HttpBackend {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+}
+
+/// Sends requests with the `curl` command, which handles TLS.
+pub struct Curl;
+
+impl HttpBackend for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        let mut child = Command::new("curl")
+            // The session is passed through stdin to keep it out of the
+            // process list
+            .args(["--silent", "--show-error", "--config", "-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("failed to run curl: {}", err))?;
+        let config = format!(
+            "url = {}\ncookie = {}\nuser-agent = {}\n",
+            quote(url),
+            quote(&format!("session={}", session)),
+            quote(USER_AGENT)
+        );
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        stdin
+            .write_all(config.as_bytes())
+            .map_err(|err| format!("failed to run curl: {}", err))?;
+        drop(stdin);
+
+        let output = child
+            .wait_with_output()
+            .map_err(|err| format!("failed to run curl: {}", err))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        let stdout = String::from_utf8(output.stdout).map_err(|_| "response is not UTF-8")?;
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or("missing status code in curl output")?;
+        Ok(Response {
+            status: status.parse().map_err(|_| "invalid status code")?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Quotes a value of a curl config file, so that it can't end its line and
+/// add options.
+fn quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\x0b' => quoted.push_str("\\v"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Where the input of a day was found.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fetched {
+    /// The input was already in `dir`, nothing was requested
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of `day` to `dir`, unless it is already there.
+pub fn fetch(
+    day: u8,
+    config: &Config,
+    backend: &impl HttpBackend,
+    dir: &Path,
+) -> Result<Fetched, String> {
+    let path = dir.join(input::file_name(day));
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let url = format!("{}/2021/day/{}/input", config.base_url, day);
+    let response = backend.get(&url, &config.session)?;
+    if response.status != 200 {
+        return Err(format!(
+            "{} returned {}: {}",
+            url,
+            response.status,
+            response.body.lines().next().unwrap_or_default()
+        ));
+    }
+
+    // Written under another name first so that an interrupted download is
+    // not mistaken for a cached input
+    fs::create_dir_all(dir)
+        .map_err(|err| format!("failed to create {}: {}", dir.display(), err))?;
+    let partial = path.with_extension("part");
+    fs::write(&partial, &response.body)
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|err| format!("failed to write {}: {}", path.display(), err))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cell::RefCell,
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Answers every request with `status`, recording the URLs and sessions.
+    struct Mock {
+        status: u16,
+        requests: RefCell<Vec<(String, String)>>,
+    }
+
+    impl Mock {
+        fn new(status: u16) -> Self {
+            Self {
+                status,
+                requests: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpBackend for Mock {
+        fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+            self.requests
+                .borrow_mut()
+                .push((url.to_string(), session.to_string()));
+            Ok(Response {
+                status: self.status,
+                body: format!("body of {}\nsecond line\n", url),
+            })
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Serves `requests` requests with the input of the day in the URL if
+    /// the session is "secret", returning the requests it received.
+    fn stub_server(requests: usize) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut received = Vec::new();
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let head = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect::<Vec<_>>();
+                let (status, body) = if head.iter().any(|h| h == "Cookie: session=secret") {
+                    let day = head[0].split('/').nth(3).unwrap().to_string();
+                    ("200 OK", format!("input of day {}\n", day))
+                } else {
+                    ("400 Bad Request", "Please log in.\n".to_string())
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                received.push(head[0].clone());
+            }
+            received
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn config() {
+        let config = Config::parse("# AoC\nsession = \"abc\"\nbase_url = \"http://a/\"\n");
+        assert_eq!(
+            config.unwrap(),
+            Config {
+                session: "abc".to_string(),
+                base_url: "http://a".to_string(),
+            }
+        );
+        assert_eq!(
+            Config::parse("session = \"abc\"").unwrap().base_url,
+            DEFAULT_BASE_URL
+        );
+        assert_eq!(Config::parse("").unwrap_err(), "missing session");
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(quote("abc"), "\"abc\"");
+        assert_eq!(
+            quote("x\"\noutput = \"/tmp/a\\"),
+            r#""x\"\noutput = \"/tmp/a\\""#
+        );
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let dir = temp_dir("mock");
+        let config = Config {
+            session: "secret".to_string(),
+            base_url: "http://a".to_string(),
+        };
+        let backend = Mock::new(200);
+        let path = dir.join("day01.txt");
+        assert_eq!(
+            fetch(1, &config, &backend, &dir),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "body of http://a/2021/day/1/input\nsecond line\n"
+        );
+        assert!(!dir.join("day01.part").exists());
+        assert_eq!(fetch(1, &config, &backend, &dir), Ok(Fetched::Cached(path)));
+
+        // An interrupted download is not a cached input
+        fs::write(dir.join("day02.part"), "body of").unwrap();
+        assert!(matches!(
+            fetch(2, &config, &backend, &dir),
+            Ok(Fetched::Downloaded(_))
+        ));
+        assert!(!dir.join("day02.part").exists());
+        assert_eq!(
+            backend.requests.into_inner(),
+            [
+                ("http://a/2021/day/1/input", "secret"),
+                ("http://a/2021/day/2/input", "secret"),
+            ]
+            .map(|(url, session)| (url.to_string(), session.to_string()))
+        );
+
+        let backend = Mock::new(404);
+        assert_eq!(
+            fetch(3, &config, &backend, &dir),
+            Err(
+                "http://a/2021/day/3/input returned 404: body of http://a/2021/day/3/input"
+                    .to_string()
+            )
+        );
+        assert!(!dir.join("day03.txt").exists());
+        assert!(!dir.join("day03.part").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn curl_against_stub_server() {
+        let dir = temp_dir("curl");
+        let (base_url, server) = stub_server(3);
+
+        let config = Config {
+            session: "secret".to_string(),
+            base_url,
+        };
+        let path = dir.join("day07.txt");
+        assert_eq!(
+            fetch(7, &config, &Curl, &dir),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input of day 7\n");
+        assert_eq!(fetch(7, &config, &Curl, &dir), Ok(Fetched::Cached(path)));
+
+        let logged_out = Config {
+            session: "expired".to_string(),
+            ..config
+        };
+        let err = fetch(8, &logged_out, &Curl, &dir).unwrap_err();
+        assert!(err.ends_with("/2021/day/8/input returned 400: Please log in."));
+        assert!(!dir.join("day08.txt").exists());
+
+        // A session can't add options to curl's config
+        let injected = dir.join("injected");
+        let injecting = Config {
+            session: format!("x\"\noutput = \"{}", injected.display()),
+            ..logged_out
+        };
+        assert!(fetch(9, &injecting, &Curl, &dir).is_err());
+        assert!(!injected.exists());
+
+        // The cached input was not requested again
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /2021/day/7/input HTTP/1.1",
+                "GET /2021/day/8/input HTTP/1.1",
+                "GET /2021/day/9/input HTTP/1.1"
+            ]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
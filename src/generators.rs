@@ -0,0 +1,186 @@
+//! Random puzzle inputs, larger or weirder than the official ones, to
+//! stress-test the solvers.
+//!
+//! Generated inputs are always accepted by the day's parser. Guarantees of the
+//! puzzle statements that the solvers rely on (e.g. a single winning bingo
+//! board) are kept where cheap, see each day's generator.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Small, seedable pseudo-random generator (SplitMix64), so that generated
+/// inputs can be reproduced from their seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform value in `low..=high`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    /// `true` with probability `1 / n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Input generator of a day.
+pub struct Generator {
+    pub day: u8,
+    /// Size used when none is given. What it counts depends on the day.
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    const fn new(day: u8, default_size: usize, generate: fn(&mut Rng, usize) -> String) -> Self {
+        Self {
+            day,
+            default_size,
+            generate,
+        }
+    }
+
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+pub const GENERATORS: [Generator; 25] = [
+    Generator::new(1, 2000, day01::generate),
+    Generator::new(2, 1000, day02::generate),
+    Generator::new(3, 1000, day03::generate),
+    Generator::new(4, 100, day04::generate),
+    Generator::new(5, 500, day05::generate),
+    Generator::new(6, 300, day06::generate),
+    Generator::new(7, 1000, day07::generate),
+    Generator::new(8, 200, day08::generate),
+    Generator::new(9, 100, day09::generate),
+    Generator::new(10, 100, day10::generate),
+    Generator::new(11, 10, day11::generate),
+    Generator::new(12, 6, day12::generate),
+    Generator::new(13, 800, day13::generate),
+    Generator::new(14, 20, day14::generate),
+    Generator::new(15, 100, day15::generate),
+    Generator::new(16, 60, day16::generate),
+    Generator::new(17, 100, day17::generate),
+    Generator::new(18, 100, day18::generate),
+    Generator::new(19, 30, day19::generate),
+    Generator::new(20, 100, day20::generate),
+    Generator::new(21, 10, day21::generate),
+    Generator::new(22, 420, day22::generate),
+    Generator::new(23, 2, day23::generate),
+    Generator::new(24, 14, day24::generate),
+    Generator::new(25, 140, day25::generate),
+];
+
+pub fn get_generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.get(usize::from(day).checked_sub(1)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day04::Bingo, get_day};
+
+    #[test]
+    fn generated_inputs_parse() {
+        for generator in &GENERATORS {
+            let day = get_day(generator.day).unwrap();
+            for seed in 0..5 {
+                for size in [1, 2, generator.default_size] {
+                    let input = generator.generate(seed, size);
+                    if let Err(err) = day.parse(&input) {
+                        panic!(
+                            "seed {}, size {}:\n{}",
+                            seed,
+                            size,
+                            err.render(&input, "generated")
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn guarantees() {
+        // Many boards are the likeliest to win several at once
+        for seed in 0..20 {
+            let input = get_generator(4).unwrap().generate(seed, 300);
+            let (drawn, mut bingo): (_, Bingo) = crate::day04::parse_input(&input).unwrap();
+            let winners = drawn
+                .into_iter()
+                .map(|n| bingo.draw(n).len())
+                .filter(|winners| *winners > 0)
+                .collect::<Vec<_>>();
+            assert_eq!(winners.first(), Some(&1), "seed {}", seed);
+            assert_eq!(winners.last(), Some(&1), "seed {}", seed);
+        }
+
+        // Small sea floors are the likeliest to never stop moving
+        for seed in 0..12 {
+            let input = get_generator(25).unwrap().generate(seed, 3);
+            let mut map = crate::day25::parse_input(&input).unwrap();
+            assert!((0..1000).any(|_| !map.step()), "seed {}:\n{}", seed, input);
+        }
+    }
+
+    #[test]
+    fn reproducible() {
+        let generator = get_generator(5).unwrap();
+        assert_eq!(generator.generate(42, 50), generator.generate(42, 50));
+        assert_ne!(generator.generate(42, 50), generator.generate(43, 50));
+    }
+}
@@ -0,0 +1,12 @@
+use super::Rng;
+
+/// `size` depth measurements, slowly drifting deeper like real sonar sweeps.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100, 200);
+    let mut input = String::new();
+    for _ in 0..size {
+        input += &format!("{}\n", depth);
+        depth = (depth + rng.range(-8, 20)).max(0);
+    }
+    input
+}
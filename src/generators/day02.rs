@@ -0,0 +1,24 @@
+use super::Rng;
+
+/// `size` commands. Down commands outweigh up ones so the submarine stays
+/// under water.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+    let mut input = String::new();
+    for _ in 0..size {
+        let units = rng.range(1, 9);
+        let command = match rng.below(3) {
+            0 if depth >= units => {
+                depth -= units;
+                "up"
+            }
+            1 => {
+                depth += units;
+                "down"
+            }
+            _ => "forward",
+        };
+        input += &format!("{} {}\n", command, units);
+    }
+    input
+}
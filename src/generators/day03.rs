@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use super::Rng;
+
+/// About `size` distinct binary numbers, always an odd count so no bit
+/// position is tied. Reports where the life support filters would discard
+/// every number are drawn again.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size | 1;
+    // Enough bits for the numbers to be sparse, like the official 12 bits
+    // for 1000 lines
+    let bits = (usize::BITS - (4 * size).leading_zeros()).max(5) as usize;
+    loop {
+        let mut values = HashSet::new();
+        while values.len() < size {
+            values.insert(rng.below(1 << bits));
+        }
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        rng.shuffle(&mut values);
+
+        if filters_keep_one(&values, bits, true) && filters_keep_one(&values, bits, false) {
+            break values
+                .iter()
+                .map(|v| format!("{:0width$b}\n", v, width = bits))
+                .collect();
+        }
+    }
+}
+
+/// Whether the oxygen (`most_common`) or CO2 filter ends with a number.
+fn filters_keep_one(values: &[usize], bits: usize, most_common: bool) -> bool {
+    let mut candidates = values.to_vec();
+    for bit in (0..bits).rev() {
+        if candidates.len() <= 1 {
+            break;
+        }
+        let ones = candidates.iter().filter(|v| *v >> bit & 1 == 1).count();
+        let zeros = candidates.len() - ones;
+        let keep = (ones >= zeros) == most_common;
+        candidates.retain(|v| (*v >> bit & 1 == 1) == keep);
+    }
+    candidates.len() == 1
+}
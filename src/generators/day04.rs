@@ -0,0 +1,46 @@
+use super::Rng;
+use crate::day04::{parse_input, Bingo, BINGO_SIZE};
+
+/// `size` boards. Boards are drawn again until a single board wins first and
+/// a single board wins last, as the puzzle guarantees.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let input = generate_once(rng, size);
+        if has_single_first_and_last_winner(&input) {
+            break input;
+        }
+    }
+}
+
+fn generate_once(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..100).collect::<Vec<u8>>();
+    rng.shuffle(&mut numbers);
+    let drawn = numbers.iter().map(u8::to_string).collect::<Vec<_>>();
+    let mut input = format!("{}\n", drawn.join(","));
+
+    for _ in 0..size.max(1) {
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..BINGO_SIZE * BINGO_SIZE].chunks(BINGO_SIZE) {
+            let row = row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>();
+            input += &format!("{}\n", row.join(" "));
+        }
+    }
+    input
+}
+
+fn has_single_first_and_last_winner(input: &str) -> bool {
+    let (drawn, mut bingo): (_, Bingo) = parse_input(input).unwrap();
+    let mut first = true;
+    for n in drawn {
+        let winners = bingo.draw(n);
+        if winners.is_empty() {
+            continue;
+        }
+        if (first || bingo.boards.is_empty()) && winners.len() > 1 {
+            return false;
+        }
+        first = false;
+    }
+    true
+}
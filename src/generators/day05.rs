@@ -0,0 +1,32 @@
+use super::Rng;
+
+/// `size` horizontal, vertical or diagonal lines on a 1000x1000 grid.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let (x1, y1) = (rng.range(0, 999), rng.range(0, 999));
+        let length = rng.range(0, 999);
+        let (dx, dy) = *rng.choose(&[
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ]);
+        // Shorten the line so it stays on the grid
+        let fits =
+            |l: i64| (0..1000).contains(&(x1 + dx * l)) && (0..1000).contains(&(y1 + dy * l));
+        let length = (0..=length).rev().find(|l| fits(*l)).unwrap_or(0);
+        input += &format!(
+            "{},{} -> {},{}\n",
+            x1,
+            y1,
+            x1 + dx * length,
+            y1 + dy * length
+        );
+    }
+    input
+}
@@ -0,0 +1,9 @@
+use super::Rng;
+
+/// `size` lanternfish timers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let timers = (0..size.max(1))
+        .map(|_| rng.range(1, 5).to_string())
+        .collect::<Vec<_>>();
+    format!("{}\n", timers.join(","))
+}
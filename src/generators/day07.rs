@@ -0,0 +1,13 @@
+use super::Rng;
+
+/// `size` crab positions, clustered around a few points.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let centers = (0..3).map(|_| rng.range(0, 1500)).collect::<Vec<_>>();
+    let positions = (0..size.max(1))
+        .map(|_| {
+            let center = *rng.choose(&centers);
+            (center + rng.range(-500, 500)).max(0).to_string()
+        })
+        .collect::<Vec<_>>();
+    format!("{}\n", positions.join(","))
+}
@@ -0,0 +1,38 @@
+use super::Rng;
+
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// `size` displays, each with its own wiring of the segments.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut wiring = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+        rng.shuffle(&mut wiring);
+
+        let mut patterns = DIGITS
+            .iter()
+            .map(|digit| wire(rng, &wiring, digit))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut patterns);
+        let output = (0..4)
+            .map(|_| {
+                let digit = *rng.choose(&DIGITS);
+                wire(rng, &wiring, digit)
+            })
+            .collect::<Vec<_>>();
+        input += &format!("{} | {}\n", patterns.join(" "), output.join(" "));
+    }
+    input
+}
+
+/// Pattern lighting up `digit` with the given wiring, in random order.
+fn wire(rng: &mut Rng, wiring: &[char; 7], digit: &str) -> String {
+    let mut pattern = digit
+        .chars()
+        .map(|c| wiring[c as usize - 'a' as usize])
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut pattern);
+    pattern.into_iter().collect()
+}
@@ -0,0 +1,30 @@
+use super::Rng;
+
+/// `size`x`size` heightmap, with ridges of 9s splitting it into basins.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    digit_grid(rng, size, size, |rng| {
+        if rng.one_in(4) {
+            9
+        } else {
+            rng.range(0, 8)
+        }
+    })
+}
+
+/// `width`x`height` grid of the digits returned by `digit`, shared with the
+/// other days' grids of digits.
+pub(super) fn digit_grid(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    mut digit: impl FnMut(&mut Rng) -> i64,
+) -> String {
+    let mut input = String::new();
+    for _ in 0..height.max(1) {
+        for _ in 0..width.max(1) {
+            input += &digit(rng).to_string();
+        }
+        input.push('\n');
+    }
+    input
+}
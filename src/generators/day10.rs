@@ -0,0 +1,37 @@
+use super::Rng;
+
+const OPENING: [char; 4] = ['(', '[', '{', '<'];
+const CLOSING: [char; 4] = [')', ']', '}', '>'];
+
+/// `size` lines, each either corrupted or incomplete as no line is complete
+/// in the puzzle. There is always an odd number of incomplete lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut incomplete = (0..size).map(|_| rng.one_in(2)).collect::<Vec<_>>();
+    if incomplete.iter().filter(|i| **i).count() % 2 == 0 {
+        incomplete[0] = !incomplete[0];
+    }
+
+    let mut input = String::new();
+    for incomplete in incomplete {
+        let length = rng.range(4, 100) as usize;
+        let mut stack = Vec::new();
+        let mut line = String::new();
+        while line.len() < length || stack.is_empty() {
+            if stack.is_empty() || rng.one_in(2) {
+                let shape = rng.below(4);
+                stack.push(shape);
+                line.push(OPENING[shape]);
+            } else {
+                line.push(CLOSING[stack.pop().unwrap()]);
+            }
+        }
+        if !incomplete {
+            let expected = *stack.last().unwrap();
+            let wrong = (expected + rng.range(1, 3) as usize) % 4;
+            line.push(CLOSING[wrong]);
+        }
+        input += &format!("{}\n", line);
+    }
+    input
+}
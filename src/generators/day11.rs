@@ -0,0 +1,14 @@
+use super::{day09::digit_grid, Rng};
+use crate::day11::EnergyLevels;
+
+/// 10x10 energy levels, the only size the puzzle uses so `size` is ignored.
+/// Some grids never flash all at once, those are drawn again.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    loop {
+        let input = digit_grid(rng, 10, 10, |rng| rng.range(0, 9));
+        let mut energy_levels: EnergyLevels = input.parse().unwrap();
+        if (0..1000).any(|_| energy_levels.step(1) == 100) {
+            break input;
+        }
+    }
+}
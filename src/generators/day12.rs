@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use super::Rng;
+
+/// A cave system with `size` small caves and about half as many big ones.
+/// Big caves are never connected to each other, otherwise there would be
+/// infinitely many paths.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut Rng, uppercase: bool| loop {
+        let base = if uppercase { b'A' } else { b'a' };
+        let name = (0..2)
+            .map(|_| (base + rng.below(26) as u8) as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+    let small = (0..size.max(1))
+        .map(|_| name(rng, false))
+        .collect::<Vec<_>>();
+    let big = (0..size.div_ceil(2))
+        .map(|_| name(rng, true))
+        .collect::<Vec<_>>();
+
+    let mut edges = HashSet::new();
+    let mut connect = |a: &str, b: &str| {
+        if a != b && !edges.contains(&(b.to_string(), a.to_string())) {
+            edges.insert((a.to_string(), b.to_string()));
+        }
+    };
+    connect("start", rng.choose(&small).as_str());
+    connect(rng.choose(&small).as_str(), "end");
+    if let Some(cave) = big.first() {
+        connect("start", cave);
+        connect(cave, "end");
+    }
+    for cave in &small {
+        for _ in 0..rng.range(1, 2) {
+            let other = if rng.one_in(2) && !big.is_empty() {
+                rng.choose(&big)
+            } else {
+                rng.choose(&small)
+            };
+            connect(cave, other);
+        }
+    }
+
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    rng.shuffle(&mut edges);
+    edges
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}\n", a, b))
+        .collect()
+}
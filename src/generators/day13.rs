@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use super::Rng;
+
+/// `size` dots on a transparent paper, folded twice along each axis down to
+/// the 40x6 code size.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let mut folds = Vec::new();
+    for _ in 0..2 {
+        folds.push(format!("fold along x={}", width));
+        width = 2 * width + 1;
+        folds.push(format!("fold along y={}", height));
+        height = 2 * height + 1;
+    }
+    // Largest folds come first
+    folds.reverse();
+
+    let mut dots = HashSet::new();
+    let size = size.clamp(1, width * height / 2);
+    while dots.len() < size {
+        let (x, y) = (rng.below(width), rng.below(height));
+        // Dots are never on a fold line
+        let on_fold = [40, 81].contains(&x) || [6, 13].contains(&y);
+        if !on_fold {
+            dots.insert((x, y));
+        }
+    }
+
+    let mut dots = dots.into_iter().collect::<Vec<_>>();
+    dots.sort();
+    rng.shuffle(&mut dots);
+    let mut input = dots
+        .into_iter()
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect::<String>();
+    input.push('\n');
+    for fold in folds {
+        input += &format!("{}\n", fold);
+    }
+    input
+}
@@ -0,0 +1,18 @@
+use super::Rng;
+
+/// A polymer template of `size` elements out of 10, with an insertion rule for
+/// every pair of elements.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elements = "BCFHKNOPSV".chars().collect::<Vec<_>>();
+    let template = (0..size.max(1))
+        .map(|_| *rng.choose(&elements))
+        .collect::<String>();
+
+    let mut input = format!("{}\n\n", template);
+    for a in &elements {
+        for b in &elements {
+            input += &format!("{}{} -> {}\n", a, b, rng.choose(&elements));
+        }
+    }
+    input
+}
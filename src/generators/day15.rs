@@ -0,0 +1,6 @@
+use super::{day09::digit_grid, Rng};
+
+/// `size`x`size` risk levels.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    digit_grid(rng, size, size, |rng| rng.range(1, 9))
+}
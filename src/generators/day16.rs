@@ -0,0 +1,81 @@
+use super::Rng;
+
+/// A transmission of about `size` nested packets. Comparison packets always
+/// have two sub-packets and products only multiply a few literals, so that
+/// values can't overflow.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut bits = Vec::new();
+    let mut budget = size.max(1);
+    packet(rng, &mut bits, &mut budget, 0);
+    // The transmission is padded with zeros to whole hex digits
+    while bits.len() % 4 != 0 {
+        bits.push(false);
+    }
+
+    let mut input = bits
+        .chunks(4)
+        .map(|nibble| {
+            let digit = nibble.iter().fold(0, |acc, bit| acc << 1 | *bit as u32);
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect::<String>();
+    input.push('\n');
+    input
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, count: usize) {
+    for i in (0..count).rev() {
+        bits.push(value >> i & 1 == 1);
+    }
+}
+
+fn literal(rng: &mut Rng, bits: &mut Vec<bool>, max: u64) {
+    push_bits(bits, rng.range(0, 7) as u64, 3);
+    push_bits(bits, 4, 3);
+    let value = rng.next_u64() % max;
+    let groups = (u64::BITS - value.leading_zeros()).div_ceil(4).max(1) as usize;
+    for group in (0..groups).rev() {
+        push_bits(bits, (group > 0) as u64, 1);
+        push_bits(bits, value >> (4 * group), 4);
+    }
+}
+
+fn packet(rng: &mut Rng, bits: &mut Vec<bool>, budget: &mut usize, depth: usize) {
+    *budget = budget.saturating_sub(1);
+    // The outermost packet is always an operator, for the transmission to
+    // have some structure
+    if *budget == 0 || depth > 20 || (depth > 0 && rng.one_in(3)) {
+        literal(rng, bits, 1 << 32);
+        return;
+    }
+
+    let type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    push_bits(bits, rng.range(0, 7) as u64, 3);
+    push_bits(bits, type_id, 3);
+
+    let mut sub_packets = Vec::new();
+    let count = match type_id {
+        1 => rng.range(1, 3) as usize,
+        5..=7 => 2,
+        _ => rng.range(1, 5) as usize,
+    };
+    for _ in 0..count {
+        let mut sub_packet = Vec::new();
+        if type_id == 1 {
+            literal(rng, &mut sub_packet, 1 << 10);
+        } else {
+            packet(rng, &mut sub_packet, budget, depth + 1);
+        }
+        sub_packets.push(sub_packet);
+    }
+
+    let length = sub_packets.iter().map(Vec::len).sum::<usize>();
+    if length < 1 << 15 && rng.one_in(2) {
+        push_bits(bits, 0, 1);
+        push_bits(bits, length as u64, 15);
+    } else {
+        push_bits(bits, 1, 1);
+        push_bits(bits, count as u64, 11);
+    }
+    bits.extend(sub_packets.into_iter().flatten());
+}
@@ -0,0 +1,14 @@
+use super::Rng;
+
+/// A target area below the submarine, at most `size` steps away horizontally.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2) as i64;
+    let x_min = rng.range(1, size);
+    let x_max = x_min + rng.range(0, size / 2);
+    let y_max = -rng.range(1, size);
+    let y_min = y_max - rng.range(0, size / 2);
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_max, y_min, y_max
+    )
+}
@@ -0,0 +1,24 @@
+use super::Rng;
+
+/// `size` reduced snailfish numbers, nested up to 4 levels deep like the
+/// puzzle's homework.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        input += &pair(rng, 1);
+        input.push('\n');
+    }
+    input
+}
+
+fn element(rng: &mut Rng, depth: usize) -> String {
+    if depth < 4 && !rng.one_in(3) {
+        pair(rng, depth + 1)
+    } else {
+        rng.range(0, 9).to_string()
+    }
+}
+
+fn pair(rng: &mut Rng, depth: usize) -> String {
+    format!("[{},{}]", element(rng, depth), element(rng, depth))
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use super::Rng;
+
+type Point = [i64; 3];
+
+/// `size` scanners, each overlapping a previous one with at least 12 beacons
+/// so that all of them can be located, and reporting beacons in its own
+/// random orientation.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut scanners: Vec<Point> = vec![[0, 0, 0]];
+    let mut beacons = HashSet::new();
+    add_beacons(rng, &mut beacons, [-1000; 3], [1000; 3], 14);
+    for _ in 1..size.max(1) {
+        // Keep scanners away from all but their parent, so that they only see
+        // about as many beacons as in the puzzle
+        let mut candidate = || {
+            let parent = *rng.choose(&scanners);
+            let scanner = parent.map(|c| c + rng.range(-1100, 1100));
+            (parent, scanner)
+        };
+        let (mut parent, mut scanner) = candidate();
+        for _ in 0..1000 {
+            let far = |other: &Point| (0..3).any(|i| (other[i] - scanner[i]).abs() > 1500);
+            if scanners.iter().filter(|s| **s != parent).all(far) {
+                break;
+            }
+            (parent, scanner) = candidate();
+        }
+        let low = [0, 1, 2].map(|i| parent[i].max(scanner[i]) - 1000);
+        let high = [0, 1, 2].map(|i| parent[i].min(scanner[i]) + 1000);
+        add_beacons(rng, &mut beacons, low, high, 12);
+        add_beacons(
+            rng,
+            &mut beacons,
+            scanner.map(|c| c - 1000),
+            scanner.map(|c| c + 1000),
+            4,
+        );
+        scanners.push(scanner);
+    }
+
+    let rotations = rotations();
+    let mut beacons = beacons.into_iter().collect::<Vec<_>>();
+    beacons.sort();
+    let mut input = String::new();
+    for (i, scanner) in scanners.iter().enumerate() {
+        if i > 0 {
+            input.push('\n');
+        }
+        input += &format!("--- scanner {} ---\n", i);
+        let rotation = rng.choose(&rotations);
+        let mut seen = beacons
+            .iter()
+            .map(|b| [0, 1, 2].map(|i| b[i] - scanner[i]))
+            .filter(|relative| relative.iter().all(|c| c.abs() <= 1000))
+            .map(|relative| rotate(rotation, relative))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut seen);
+        for [x, y, z] in seen {
+            input += &format!("{},{},{}\n", x, y, z);
+        }
+    }
+    input
+}
+
+/// Adds `count` new beacons within `low..=high`.
+fn add_beacons(rng: &mut Rng, beacons: &mut HashSet<Point>, low: Point, high: Point, count: usize) {
+    let mut added = 0;
+    while added < count {
+        let beacon = [0, 1, 2].map(|i| rng.range(low[i], high[i]));
+        if beacons.insert(beacon) {
+            added += 1;
+        }
+    }
+}
+
+/// Rotation as the source axis and sign of each coordinate.
+type Rotation = [(usize, i64); 3];
+
+/// The 24 rotations: axis permutations and signs with a determinant of 1.
+fn rotations() -> Vec<Rotation> {
+    let permutations = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let mut rotations = Vec::new();
+    for (i, axes) in permutations.into_iter().enumerate() {
+        // Odd permutations (swapping two axes) flip the orientation
+        let parity = if [1, 2, 5].contains(&i) { -1 } else { 1 };
+        for signs in 0..8 {
+            let sign = |bit: usize| if signs >> bit & 1 == 1 { -1 } else { 1 };
+            if sign(0) * sign(1) * sign(2) == parity {
+                rotations.push([0, 1, 2].map(|c| (axes[c], sign(c))));
+            }
+        }
+    }
+    rotations
+}
+
+fn rotate(rotation: &Rotation, point: Point) -> Point {
+    rotation.map(|(axis, sign)| sign * point[axis])
+}
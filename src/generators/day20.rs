@@ -0,0 +1,20 @@
+use super::Rng;
+
+/// A random enhancement algorithm and a `size`x`size` image. When an empty
+/// neighbourhood lights a pixel, a full one turns it off, otherwise the
+/// infinite image would stay lit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let pixel = |rng: &mut Rng| if rng.one_in(2) { '#' } else { '.' };
+    let mut algorithm = (0..512).map(|_| pixel(rng)).collect::<Vec<_>>();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+
+    let mut input = algorithm.into_iter().collect::<String>();
+    input += "\n\n";
+    for _ in 0..size.max(1) {
+        input += &(0..size.max(1)).map(|_| pixel(rng)).collect::<String>();
+        input.push('\n');
+    }
+    input
+}
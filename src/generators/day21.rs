@@ -0,0 +1,10 @@
+use super::Rng;
+
+/// Random starting positions, `size` is ignored.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.range(1, 10),
+        rng.range(1, 10)
+    )
+}
@@ -0,0 +1,33 @@
+use super::Rng;
+
+/// `size` reboot steps. The first fifth stays within the initialization
+/// region, like in the puzzle, and starts by turning cubes on.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::new();
+    for i in 0..size {
+        let (extent, max_size) = if i < size.div_ceil(5) {
+            (50, 50)
+        } else {
+            (100_000, 30_000)
+        };
+        let range = |rng: &mut Rng| {
+            let start = rng.range(-extent, extent - 1);
+            let end = (start + rng.range(0, max_size)).min(extent);
+            format!("{}..{}", start, end)
+        };
+        let action = if i < 2 || rng.below(4) != 0 {
+            "on"
+        } else {
+            "off"
+        };
+        input += &format!(
+            "{} x={},y={},z={}\n",
+            action,
+            range(rng),
+            range(rng),
+            range(rng)
+        );
+    }
+    input
+}
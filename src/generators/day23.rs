@@ -0,0 +1,15 @@
+use super::Rng;
+
+/// Burrow with two amphipods of each type shuffled in the rooms. The solver
+/// unfolds the rooms itself for part 2, so `size` is ignored.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    let mut amphipods = ['A', 'B', 'C', 'D'].repeat(2);
+    rng.shuffle(&mut amphipods);
+    let [a, b, c, d, e, f, g, h] = amphipods[..] else {
+        unreachable!()
+    };
+    format!(
+        "#############\n#...........#\n###{}#{}#{}#{}###\n  #{}#{}#{}#{}#\n  #########\n",
+        a, b, c, d, e, f, g, h
+    )
+}
@@ -0,0 +1,39 @@
+use super::Rng;
+use crate::day24::{MAX_MODEL_NUMBER, MIN_MODEL_NUMBER};
+
+/// Digits where the MONAD pops its base 26 stack instead of pushing. The
+/// digits of the recorded model numbers are paired the same way.
+const POPS: [bool; 14] = [
+    false, false, false, false, false, true, false, true, true, false, true, true, true, true,
+];
+
+/// A MONAD program with random constants that still accepts the recorded
+/// largest and smallest model numbers, as the solver only checks those.
+/// `size` is ignored.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    let mut input = String::new();
+    let mut pushed = Vec::new();
+    for (digit, pop) in POPS.into_iter().enumerate() {
+        let (divisor, check, offset) = if pop {
+            let (push_digit, push_offset) = pushed.pop().unwrap();
+            let difference = MAX_MODEL_NUMBER[digit] as i64 - MAX_MODEL_NUMBER[push_digit] as i64;
+            debug_assert_eq!(
+                difference,
+                MIN_MODEL_NUMBER[digit] as i64 - MIN_MODEL_NUMBER[push_digit] as i64
+            );
+            (26, difference - push_offset, rng.range(0, 16))
+        } else {
+            let offset = rng.range(0, 16);
+            pushed.push((digit, offset));
+            // Checks above 9 can't match an input digit, so the digit is pushed
+            (1, rng.range(10, 15), offset)
+        };
+        input += &format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y\n",
+            divisor, check, offset
+        );
+    }
+    input
+}
@@ -0,0 +1,31 @@
+use super::Rng;
+use crate::day25::parse_input;
+
+/// `size`x`size` sea floor, a bit more than half of it covered by cucumbers.
+/// Maps where the herds still move after a few times more steps than cells
+/// wide are drawn again, as they often never stop on a torus.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let input = generate_once(rng, size);
+        if stops_within(&input, 4 * size + 100) {
+            break input;
+        }
+    }
+}
+
+fn generate_once(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(*rng.choose(&['>', '>', 'v', 'v', '.', '.', '.']));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn stops_within(input: &str, max_steps: usize) -> bool {
+    let mut map = parse_input(input).unwrap();
+    (0..max_steps).any(|_| !map.step())
+}
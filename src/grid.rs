@@ -0,0 +1,306 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{ParseError, Parser};
+
+/// Position of a cell, as `(row, col)`.
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+const ALL: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a map with one char per cell, mapped with `f`. Rows must all
+    /// have the same length and there must be at least one.
+    pub fn parse(
+        parser: &Parser,
+        s: &str,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut lines = s.lines().peekable();
+        let width = lines
+            .peek()
+            .map(|line| line.chars().count())
+            .filter(|width| *width > 0)
+            .ok_or_else(|| parser.error(s, expected))?;
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines {
+            let row = parser.chars(line, expected, &f)?;
+            if row.len() != width {
+                return Err(parser.error(line, format!("row of {} cells", width)));
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Position `delta` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let row = row
+            .checked_add_signed(dr)
+            .filter(|row| *row < self.height)?;
+        let col = col.checked_add_signed(dc).filter(|col| *col < self.width)?;
+        Some((row, col))
+    }
+
+    /// Position `delta` away from `pos`, wrapping around the edges.
+    pub fn wrapping_offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Pos {
+        (
+            (row as isize + dr).rem_euclid(self.height as isize) as usize,
+            (col as isize + dc).rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    /// Up, left, right and down neighbours inside the grid.
+    pub fn orthogonal_neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    pub fn diagonal_neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIAGONAL
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Orthogonal and diagonal neighbours, row by row.
+    pub fn all_neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL.into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Orthogonal neighbours, as if opposite edges were joined.
+    pub fn toroidal_neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .map(move |delta| self.wrapping_offset(pos, delta))
+    }
+
+    /// Positions orthogonally connected to `start` through cells accepted by
+    /// `f`, `start` included if accepted.
+    pub fn region(&self, start: Pos, f: impl Fn(&T) -> bool) -> Vec<Pos> {
+        let mut visited = self.map(|_| false);
+        self.fill(start, &f, &mut visited)
+    }
+
+    /// Splits the cells accepted by `f` into orthogonally connected regions.
+    pub fn regions(&self, f: impl Fn(&T) -> bool) -> Vec<Vec<Pos>> {
+        let mut visited = self.map(|_| false);
+        self.positions()
+            .filter_map(|pos| {
+                let region = self.fill(pos, &f, &mut visited);
+                (!region.is_empty()).then_some(region)
+            })
+            .collect()
+    }
+
+    fn fill(&self, start: Pos, f: &impl Fn(&T) -> bool, visited: &mut Grid<bool>) -> Vec<Pos> {
+        let mut region = Vec::new();
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            if visited[pos] || !f(&self[pos]) {
+                continue;
+            }
+            visited[pos] = true;
+            region.push(pos);
+            stack.extend(self.orthogonal_neighbours(pos));
+        }
+        region
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of the grid", pos))
+    }
+}
+
+/// Every row on its own line, cells written next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE: &str = "1199
+1919
+9911
+";
+
+    fn digit(c: char) -> Option<u8> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+
+    #[test]
+    fn example() {
+        let parser = Parser::new(0, INPUT_EXAMPLE);
+        let grid = Grid::parse(&parser, INPUT_EXAMPLE, "digit", digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid[(1, 2)], 1);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT_EXAMPLE);
+
+        assert_eq!(
+            grid.orthogonal_neighbours((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.diagonal_neighbours((0, 0)).collect::<Vec<_>>(),
+            [(1, 1)]
+        );
+        assert_eq!(grid.all_neighbours((1, 1)).count(), 8);
+        assert_eq!(
+            grid.toroidal_neighbours((0, 0)).collect::<Vec<_>>(),
+            [(2, 0), (0, 3), (0, 1), (1, 0)]
+        );
+
+        let mut region = grid.region((0, 0), |d| *d == 1);
+        region.sort_unstable();
+        assert_eq!(region, [(0, 0), (0, 1), (1, 0)]);
+        assert!(grid.region((0, 2), |d| *d == 1).is_empty());
+
+        let sizes = grid
+            .regions(|d| *d == 1)
+            .iter()
+            .map(Vec::len)
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [3, 3]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s| Grid::parse(&Parser::new(0, s), s, "digit", digit);
+
+        let err = parse("12\n1x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "digit");
+
+        let err = parse("12\n123\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "row of 2 cells");
+
+        assert!(parse("").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `-` means stdin, any other value is a path, and no value falls back
+    /// to `inputs/dayNN.txt`.
+    pub fn new(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+            None => Self::File(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Directory of the puzzle inputs.
+pub const INPUTS_DIR: &str = "inputs";
+
+pub fn file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(INPUTS_DIR).join(file_name(day))
+}
+
+/// Reads the input for a `dayNN` binary from its first command line argument,
+/// exiting with an error message if it can't be read.
+pub fn from_args(day: u8) -> (InputSource, String) {
+    let arg = std::env::args().nth(1);
+    let source = InputSource::new(day, arg.as_deref());
+    match source.read() {
+        Ok(input) => (source, input),
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", source, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources() {
+        assert_eq!(InputSource::new(7, Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::new(7, Some("example.txt")),
+            InputSource::File(PathBuf::from("example.txt"))
+        );
+        assert_eq!(
+            InputSource::new(7, None),
+            InputSource::File(PathBuf::from("inputs/day07.txt"))
+        );
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    }
+}
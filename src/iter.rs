@@ -0,0 +1,60 @@
+/// Iterator over the overlapping windows of `N` consecutive items of another
+/// iterator, created by [`IteratorExt::array_windows`].
+#[derive(Debug, Clone)]
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            Some(window) => {
+                window.rotate_left(1);
+                window[N - 1] = self.iter.next()?;
+            }
+            None => {
+                let first = self.iter.by_ref().take(N).collect::<Vec<_>>();
+                self.window = Some(first.try_into().ok()?);
+            }
+        }
+        self.window.clone()
+    }
+}
+
+pub trait IteratorExt: Iterator + Sized {
+    /// Windows of `N` consecutive items, without collecting the items first.
+    /// Like `slice::windows`, nothing is returned if there are fewer than `N`
+    /// items.
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N> {
+        assert!(N > 0, "windows must not be empty");
+        ArrayWindows {
+            iter: self,
+            window: None,
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows() {
+        let windows = (1..=4).array_windows().collect::<Vec<[i32; 2]>>();
+        assert_eq!(windows, [[1, 2], [2, 3], [3, 4]]);
+
+        let windows = (1..=4).array_windows().collect::<Vec<[i32; 4]>>();
+        assert_eq!(windows, [[1, 2, 3, 4]]);
+
+        assert_eq!((1..=2).array_windows::<3>().next(), None);
+    }
+}
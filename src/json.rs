@@ -0,0 +1,41 @@
+/// Encodes `s` as a JSON string, with its quotes.
+pub fn string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Formats already encoded objects as a JSON array, one per line.
+pub fn array(objects: &[String]) -> String {
+    if objects.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n  {}\n]\n", objects.join(",\n  "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode() {
+        assert_eq!(string("1559"), r#""1559""#);
+        assert_eq!(string("█ \"a\"\\\n\u{1}"), r#""█ \"a\"\\\n\u0001""#);
+        assert_eq!(array(&[]), "[]\n");
+        assert_eq!(
+            array(&["1".to_string(), "2".to_string()]),
+            "[\n  1,\n  2\n]\n"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseError, solution::Day};
+
+/// A part to solve for an input.
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    pub day: &'static Day,
+    pub part: usize,
+    /// The input, or why it couldn't be read
+    pub input: Result<&'a str, &'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    /// The input couldn't be read, with the reason
+    ReadError(String),
+    ParseError(ParseError),
+    /// The solver panicked, with its panic message
+    Panic(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: usize,
+    pub outcome: Outcome,
+    /// Time spent solving the part, parsing excluded
+    pub elapsed: Duration,
+}
+
+/// Solves every job on a pool of `threads` threads, returning the results
+/// sorted by day and part. A panic only fails the job that raised it.
+///
+/// Every job parses its input again, so that parts of the same day don't
+/// wait for each other.
+pub fn run(jobs: &[Job], threads: usize) -> Vec<PartResult> {
+    let next = AtomicUsize::new(0);
+    let mut results = thread::scope(|scope| {
+        let workers = (0..threads.clamp(1, jobs.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        results.push(run_job(job));
+                    }
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("jobs don't panic outside of solvers"))
+            .collect::<Vec<_>>()
+    });
+    results.sort_by_key(|r| (r.day, r.part));
+    results
+}
+
+fn run_job(job: &Job) -> PartResult {
+    let mut elapsed = Duration::ZERO;
+    let outcome = match job.input {
+        Ok(input) => {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                let parsed = job.day.parse(input)?;
+                let start = Instant::now();
+                let answer = job.day.solve(parsed.as_ref(), job.part);
+                elapsed = start.elapsed();
+                Ok(answer)
+            }));
+            match outcome {
+                Ok(Ok(answer)) => Outcome::Answer(answer),
+                Ok(Err(err)) => Outcome::ParseError(err),
+                Err(payload) => Outcome::Panic(panic_message(payload.as_ref())),
+            }
+        }
+        Err(reason) => Outcome::ReadError(reason.to_string()),
+    };
+    PartResult {
+        day: job.day.day,
+        part: job.part,
+        outcome,
+        elapsed,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day;
+
+    #[test]
+    fn isolated_jobs() {
+        let day01 = get_day(1).unwrap();
+        let day09 = get_day(9).unwrap();
+        let jobs = [
+            Job {
+                day: day09,
+                part: 1,
+                input: Ok("123\n"),
+            },
+            Job {
+                day: day01,
+                part: 2,
+                input: Ok("199\n200\n208\n210\n"),
+            },
+            Job {
+                day: day01,
+                part: 1,
+                input: Ok("199\n200\n208\n210\n"),
+            },
+            Job {
+                day: day01,
+                part: 3,
+                input: Ok("199\n"),
+            },
+            Job {
+                day: day09,
+                part: 2,
+                input: Ok("12x\n"),
+            },
+            Job {
+                day: get_day(10).unwrap(),
+                part: 1,
+                input: Err("failed to read inputs/day10.txt: not found"),
+            },
+        ];
+        let results = run(&jobs, 3);
+
+        let summary = results
+            .iter()
+            .map(|r| (r.day, r.part, &r.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(summary[0], (1, 1, &Outcome::Answer("3".to_string())));
+        assert_eq!(summary[1], (1, 2, &Outcome::Answer("1".to_string())));
+        assert_eq!(
+            summary[2],
+            (1, 3, &Outcome::Panic("invalid part 3".to_string()))
+        );
+        assert_eq!(summary[3], (9, 1, &Outcome::Answer("2".to_string())));
+        assert!(matches!(summary[4], (9, 2, Outcome::ParseError(_))));
+        assert_eq!(
+            summary[5],
+            (
+                10,
+                1,
+                &Outcome::ReadError("failed to read inputs/day10.txt: not found".to_string())
+            )
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use crate::trace;
+
+/// State of a weighted search, e.g. a position in a maze.
+pub trait SearchState: Clone + Eq + Hash {
+    /// States reachable in one move, with the cost of that move.
+    fn successors(&self) -> Vec<(Self, usize)>;
+
+    fn is_goal(&self) -> bool;
+
+    /// Lower bound of the cost left to reach a goal, used by [`astar`]. It
+    /// must never overestimate, otherwise the path found may not be the
+    /// cheapest.
+    fn heuristic(&self) -> usize {
+        0
+    }
+}
+
+/// Cheapest path found by a search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Found<S> {
+    pub cost: usize,
+    /// States from the start to the goal, both included
+    pub path: Vec<S>,
+}
+
+/// Cheapest path from `start` to a goal, ignoring the heuristic.
+pub fn dijkstra<S: SearchState>(start: S) -> Option<Found<S>> {
+    search(start, |_| 0)
+}
+
+/// Cheapest path from `start` to a goal, exploring first the states whose
+/// heuristic looks best.
+pub fn astar<S: SearchState>(start: S) -> Option<Found<S>> {
+    search(start, S::heuristic)
+}
+
+struct Node<S> {
+    state: S,
+    cost: usize,
+    parent: Option<usize>,
+}
+
+fn search<S: SearchState>(start: S, heuristic: impl Fn(&S) -> usize) -> Option<Found<S>> {
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut nodes = vec![Node {
+        state: start,
+        cost: 0,
+        parent: None,
+    }];
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if cost > nodes[id].cost {
+            // A cheaper way to this state was found after it was queued
+            continue;
+        }
+        if nodes[id].state.is_goal() {
+            trace!(Debug, "cost {} after reaching {} states", cost, nodes.len());
+            return Some(Found {
+                cost,
+                path: path_to(&nodes, id),
+            });
+        }
+
+        for (next, step_cost) in nodes[id].state.successors() {
+            let next_cost = cost + step_cost;
+            let priority = next_cost + heuristic(&next);
+            let next_id = match ids.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_id = *entry.get();
+                    if next_cost >= nodes[next_id].cost {
+                        continue;
+                    }
+                    nodes[next_id].cost = next_cost;
+                    nodes[next_id].parent = Some(id);
+                    next_id
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        cost: next_cost,
+                        parent: Some(id),
+                    });
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+            queue.push(Reverse((priority, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+fn path_to<S: Clone>(nodes: &[Node<S>], mut id: usize) -> Vec<S> {
+    let mut path = vec![nodes[id].state.clone()];
+    while let Some(parent) = nodes[id].parent {
+        path.push(nodes[parent].state.clone());
+        id = parent;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Walking along a number line towards 10, with a toll between 4 and 5
+    /// that jumping over from 3 to 6 avoids.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Walk(i32);
+
+    impl SearchState for Walk {
+        fn successors(&self) -> Vec<(Self, usize)> {
+            let mut successors = vec![
+                (Walk(self.0 - 1), 1),
+                (Walk(self.0 + 1), if self.0 == 4 { 10 } else { 1 }),
+            ];
+            if self.0 == 3 {
+                successors.push((Walk(6), 5));
+            }
+            successors.retain(|(walk, _)| (0..=10).contains(&walk.0));
+            successors
+        }
+
+        fn is_goal(&self) -> bool {
+            self.0 == 10
+        }
+
+        fn heuristic(&self) -> usize {
+            self.0.abs_diff(10) as usize
+        }
+    }
+
+    #[test]
+    fn example() {
+        let expected = Found {
+            cost: 12,
+            path: [0, 1, 2, 3, 6, 7, 8, 9, 10].map(Walk).to_vec(),
+        };
+        assert_eq!(dijkstra(Walk(0)), Some(expected.clone()));
+        assert_eq!(astar(Walk(0)), Some(expected));
+
+        assert_eq!(dijkstra(Walk(12)), None);
+    }
+}
@@ -0,0 +1,126 @@
+use std::{any::Any, fmt::Display};
+
+use crate::{error::ParseError, input, trace};
+
+pub trait Solution {
+    const DAY: u8;
+    /// Number of parts with a puzzle, only day 25 has a single one.
+    const PARTS: usize = 2;
+
+    type Input: 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Answer of a part without a puzzle.
+#[derive(Debug, PartialEq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// A [`Solution`] with its types erased, so that all days can be listed and
+/// run the same way.
+pub struct Day {
+    pub day: u8,
+    pub parts: usize,
+    parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    solve: fn(&dyn Any, usize) -> String,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            parts: S::PARTS,
+            parse: parse_erased::<S>,
+            solve: solve_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves `part` for an input returned by [`Day::parse`] of the same day.
+    pub fn solve(&self, input: &dyn Any, part: usize) -> String {
+        (self.solve)(input, part)
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn solve_erased<S: Solution>(input: &dyn Any, part: usize) -> String {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by another day");
+    match part {
+        1 => S::part1(input).to_string(),
+        2 => S::part2(input).to_string(),
+        _ => panic!("invalid part {}", part),
+    }
+}
+
+/// Entry point of the `dayNN` binaries.
+pub fn run<S: Solution>() {
+    run_and_then::<S>(|_| ());
+}
+
+/// Like [`run`], then calls `f` with the parsed input, for binaries printing
+/// more than the answers.
+pub fn run_and_then<S: Solution>(f: impl FnOnce(&S::Input)) {
+    if let Err(err) = trace::init_from_env() {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    }
+    let (source, input) = input::from_args(S::DAY);
+    let input = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprint!("{}", err.render(&input, &source.to_string()));
+            std::process::exit(1);
+        }
+    };
+    print_answer(1, S::part1(&input));
+    if S::PARTS > 1 {
+        print_answer(2, S::part2(&input));
+    }
+    f(&input);
+}
+
+fn print_answer(part: usize, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        print!("Answer {}:\n{}", part, answer);
+    } else {
+        println!("Answer {}: {}", part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::Day01;
+
+    #[test]
+    fn erased_day() {
+        let day = Day::new::<Day01>();
+        assert_eq!(day.day, 1);
+        assert_eq!(day.parts, 2);
+
+        let input = day
+            .parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n")
+            .unwrap();
+        assert_eq!(day.solve(input.as_ref(), 1), "7");
+        assert_eq!(day.solve(input.as_ref(), 2), "5");
+    }
+}
@@ -0,0 +1,173 @@
+//! Opt-in tracing of what the solvers do, printed to stderr.
+//!
+//! Tracing is configured with a comma separated list of directives, each a
+//! level optionally preceded by a target, e.g. `debug` or `day23=trace,debug`.
+//! The target of an event is the module that emits it (`day23`, `search`...)
+//! and the most specific directive matching it applies.
+
+use std::{
+    fmt::Display,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+/// Environment variable read by [`init_from_env`].
+pub const ENV_VAR: &str = "AOC_TRACE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// Results and milestones of a solver
+    Info,
+    /// Progress of a solver, a few lines per step
+    Debug,
+    /// Every state a solver goes through
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!("invalid trace level: {}", s)),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Off => "off",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parsed tracing configuration.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    /// Level of the events without a more specific directive
+    default: Option<Level>,
+    targets: Vec<(String, Level)>,
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    filter.targets.push((target.to_string(), level.parse()?));
+                }
+                None => filter.default = Some(directive.parse()?),
+            }
+        }
+        Ok(filter)
+    }
+}
+
+impl Filter {
+    pub fn level(&self, target: &str) -> Level {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(t, _)| t == target)
+            .map(|(_, level)| *level)
+            .or(self.default)
+            .unwrap_or(Level::Off)
+    }
+
+    fn max_level(&self) -> Level {
+        let targets = self.targets.iter().map(|(_, level)| *level);
+        targets.chain(self.default).max().unwrap_or(Level::Off)
+    }
+}
+
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+/// Most verbose level of the filter, so that events are skipped without
+/// locking while tracing is off
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+/// Enables the events matching `filter`, replacing the previous filter.
+pub fn init(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap() = Some(filter);
+}
+
+/// Initializes tracing from [`ENV_VAR`] if it is set.
+pub fn init_from_env() -> Result<(), String> {
+    if let Ok(spec) = std::env::var(ENV_VAR) {
+        init(
+            spec.parse()
+                .map_err(|err| format!("{}: {}", ENV_VAR, err))?,
+        );
+    }
+    Ok(())
+}
+
+pub fn enabled(target: &str, level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    FILTER
+        .read()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|filter| level <= filter.level(target))
+}
+
+/// Target of events emitted from `module_path`: its last segment.
+pub fn target(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+/// Prints an event to stderr if its level is enabled for the calling module.
+///
+/// ```
+/// # use advent_of_code_2021::trace;
+/// trace!(Debug, "explored {} states", 42);
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {{
+        let level = $crate::trace::Level::$level;
+        let target = $crate::trace::target(module_path!());
+        if $crate::trace::enabled(target, level) {
+            eprintln!("[{} {}] {}", level, target, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter() {
+        let filter: Filter = "day23=trace, info,day15=off".parse().unwrap();
+        assert_eq!(filter.level("day23"), Level::Trace);
+        assert_eq!(filter.level("day15"), Level::Off);
+        assert_eq!(filter.level("day12"), Level::Info);
+        assert_eq!(filter.max_level(), Level::Trace);
+
+        assert_eq!(Filter::default().level("day12"), Level::Off);
+        assert_eq!(
+            "day23=loud".parse::<Filter>().unwrap_err(),
+            "invalid trace level: loud"
+        );
+        assert_eq!(target("advent_of_code_2021::day23"), "day23");
+    }
+}